@@ -1,4 +1,5 @@
 use clap::{Parser, Subcommand, ValueEnum};
+use monorepo_cli::{CheckTarget, Lang, Status};
 use std::path::PathBuf;
 
 #[derive(Debug, Parser)]
@@ -7,35 +8,197 @@ use std::path::PathBuf;
 pub struct Cli {
     #[command(subcommand)]
     pub command: Commands,
+
+    /// Output language (defaults to LC_ALL/LC_MESSAGES/LANG)
+    #[arg(long, value_enum, global = true)]
+    pub lang: Option<Lang>,
 }
 
 #[derive(Debug, Subcommand)]
 pub enum Commands {
     Check(CheckArgs),
+    /// Explains what a finding checks and how to fix it
+    Explain(ExplainArgs),
+    /// Packages the deployable artifacts after the readiness checks pass
+    Bundle(BundleArgs),
+    /// Generates deployment files the checks expect
+    #[command(subcommand)]
+    Scaffold(ScaffoldCommand),
+    /// Checks the health endpoints of a running deployment
+    Probe(ProbeArgs),
 }
 
 #[derive(Debug, Parser)]
 pub struct CheckArgs {
+    /// One or more targets, e.g. `check env ci`
     #[arg(value_enum, default_value = "all")]
-    pub target: CheckTarget,
+    pub targets: Vec<CheckTarget>,
 
     #[arg(long, default_value = "..")]
     pub root: PathBuf,
 
+    #[arg(long, default_value = "dev")]
+    pub profile: String,
+
     #[arg(long)]
+    pub config: Option<PathBuf>,
+
+    #[arg(long, value_enum, default_value = "human")]
+    pub format: OutputFormat,
+
+    /// Shorthand for `--format json`
+    #[arg(long, conflicts_with = "format")]
     pub json: bool,
 
+    /// Write the report to a file instead of stdout
+    #[arg(long)]
+    pub out: Option<PathBuf>,
+
     #[arg(long)]
     pub strict: bool,
+
+    /// Leave out finding ids matching a glob such as `nginx.*` (repeatable)
+    #[arg(long, value_name = "GLOB")]
+    pub skip: Vec<String>,
+
+    /// Only report findings at or above this status
+    #[arg(long, value_enum, default_value = "ok")]
+    pub min_status: Status,
+
+    /// Only let finding ids matching a glob decide the exit code (repeatable)
+    #[arg(long, value_name = "GLOB")]
+    pub fail_on: Vec<String>,
+
+    /// List every known finding id instead of running checks
+    #[arg(long)]
+    pub list: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    Human,
+    Json,
+    /// Single self-contained HTML page
+    Html,
+    /// Prometheus text format for node_exporter's textfile collector
+    Prometheus,
+}
+
+#[derive(Debug, Parser)]
+pub struct ExplainArgs {
+    /// Finding id such as `pm2.ecosystem`, or a target such as `pm2`
+    pub id: String,
+
+    #[arg(long, default_value = "..")]
+    pub root: PathBuf,
+
+    #[arg(long)]
+    pub config: Option<PathBuf>,
+}
+
+#[derive(Debug, Parser)]
+#[command(args_conflicts_with_subcommands = true)]
+pub struct BundleArgs {
+    #[command(subcommand)]
+    pub command: Option<BundleCommand>,
+
+    #[arg(long, default_value = "release.tar.gz")]
+    pub out: PathBuf,
+
+    #[arg(long, default_value = "..")]
+    pub root: PathBuf,
+
+    #[arg(long, default_value = "prod")]
+    pub profile: String,
+
+    #[arg(long)]
+    pub config: Option<PathBuf>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum BundleCommand {
+    /// Checks an existing bundle against its manifest
+    Verify(VerifyArgs),
+}
+
+#[derive(Debug, Parser)]
+pub struct VerifyArgs {
+    pub archive: PathBuf,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ScaffoldCommand {
+    /// Writes one systemd unit per backend process type to deploy/systemd/
+    Systemd(SystemdArgs),
 }
 
-#[derive(Debug, Clone, Copy, ValueEnum)]
-pub enum CheckTarget {
-    All,
-    Nginx,
-    Pm2,
-    Build,
-    Env,
-    Ci,
-    Runtime,
+#[derive(Debug, Parser)]
+pub struct SystemdArgs {
+    #[arg(long, default_value = "..")]
+    pub root: PathBuf,
+
+    /// Profile whose env file provides PORT and ADMIN_PORT
+    #[arg(long, default_value = "prod")]
+    pub profile: String,
+
+    #[arg(long)]
+    pub config: Option<PathBuf>,
+
+    /// Absolute path of the checkout on the server
+    #[arg(long, default_value = "/srv/app")]
+    pub install_dir: String,
+
+    #[arg(long, default_value = "app")]
+    pub user: String,
+
+    /// Absolute path of the bun executable on the server
+    #[arg(long, default_value = "/usr/local/bin/bun")]
+    pub bun: String,
+
+    /// Overwrite existing units
+    #[arg(long)]
+    pub force: bool,
+}
+
+#[derive(Debug, Parser)]
+pub struct ProbeArgs {
+    /// Base URL of the main server
+    #[arg(long, default_value = "http://localhost:8000")]
+    pub url: String,
+
+    /// Base URL of the admin server [default: the main URL with ADMIN_PORT]
+    #[arg(long)]
+    pub admin_url: Option<String>,
+
+    #[arg(long, default_value = "..")]
+    pub root: PathBuf,
+
+    /// `dev` expects /openapi/json to be served; other profiles expect it hidden
+    #[arg(long, default_value = "dev")]
+    pub profile: String,
+
+    #[arg(long)]
+    pub config: Option<PathBuf>,
+
+    /// Attempts per endpoint, with exponential backoff between them
+    #[arg(long, default_value_t = 5)]
+    pub retries: u32,
+
+    /// Timeout of a single request, in seconds
+    #[arg(long, default_value_t = 5)]
+    pub timeout: u64,
+
+    #[arg(long, value_enum, default_value = "human")]
+    pub format: OutputFormat,
+
+    /// Shorthand for `--format json`
+    #[arg(long, conflicts_with = "format")]
+    pub json: bool,
+
+    /// Write the report to a file instead of stdout
+    #[arg(long)]
+    pub out: Option<PathBuf>,
+
+    #[arg(long)]
+    pub strict: bool,
 }
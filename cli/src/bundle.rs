@@ -0,0 +1,448 @@
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use crate::error::CliError;
+use crate::report::{GitInfo, Report};
+use crate::util::path::slash;
+use crate::util::repo::nginx_config_files;
+use crate::util::time::rfc3339;
+use crate::util::walk::walk_all;
+
+/// Name of the manifest stored at the top of every bundle.
+pub const MANIFEST: &str = "manifest.json";
+
+const MANIFEST_VERSION: u32 = 1;
+
+// Deployable directories, relative to the repo root.
+const DIRS: [&str; 3] = ["apps/be/dist", "apps/fe/dist", "apps/be/prisma"];
+
+// Per directory in DIRS; a bundle is never partial.
+const MAX_FILES: usize = 50_000;
+
+const ECOSYSTEM_FILES: [&str; 5] = [
+    "ecosystem.config.js",
+    "ecosystem.config.cjs",
+    "ecosystem.config.json",
+    "ecosystem.config.yml",
+    "ecosystem.config.yaml",
+];
+
+/// Contents of `manifest.json`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Manifest {
+    pub manifest_version: u32,
+    pub created_at: String,
+    pub cli_version: String,
+    #[serde(default)]
+    pub git: Option<GitInfo>,
+    pub files: Vec<ManifestFile>,
+    /// Readiness report the bundle was created with.
+    pub report: Report,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ManifestFile {
+    pub path: String,
+    pub size: u64,
+    pub sha256: String,
+}
+
+/// Result of checking an archive against its manifest.
+#[derive(Debug)]
+pub struct Verification {
+    pub manifest: Manifest,
+    pub problems: Vec<String>,
+}
+
+impl Verification {
+    pub fn is_ok(&self) -> bool {
+        self.problems.is_empty()
+    }
+}
+
+/// Files that go into a bundle, relative to `root` and sorted.
+pub fn collect_files(root: &Path) -> Result<Vec<PathBuf>, CliError> {
+    let mut files = Vec::new();
+    for dir in DIRS {
+        let path = root.join(dir);
+        if !path.is_dir() {
+            return Err(CliError::Bundle(format!("{dir} not found")));
+        }
+        // Build output is gitignored, so ignore files must not apply here.
+        let walk = walk_all(&path, MAX_FILES);
+        if walk.truncated {
+            return Err(CliError::Bundle(format!(
+                "{dir} has more than {MAX_FILES} files"
+            )));
+        }
+        files.extend(walk.files.into_iter().filter(|p| !is_local_database(p)));
+    }
+
+    let ecosystem = ECOSYSTEM_FILES
+        .iter()
+        .map(|f| root.join(f))
+        .find(|p| p.is_file())
+        .ok_or_else(|| CliError::Bundle("no ecosystem.config.* at the repo root".to_string()))?;
+    files.push(ecosystem);
+
+    let nginx = nginx_config_files(root);
+    if nginx.is_empty() {
+        return Err(CliError::Bundle("no nginx config in the repo".to_string()));
+    }
+    files.extend(nginx);
+
+    let mut rel: Vec<PathBuf> = files
+        .into_iter()
+        .filter_map(|p| p.strip_prefix(root).ok().map(Path::to_path_buf))
+        .collect();
+    rel.sort();
+    rel.dedup();
+    Ok(rel)
+}
+
+// The SQLite dev database never ships; the server has its own DATABASE_URL.
+fn is_local_database(p: &Path) -> bool {
+    let name = p
+        .file_name()
+        .map(|n| n.to_string_lossy())
+        .unwrap_or_default();
+    name.ends_with(".db") || name.ends_with(".db-journal") || name.ends_with(".sqlite")
+}
+
+/// Writes a gzipped tarball of the deployable artifacts plus `manifest.json` to `out`.
+pub fn create(root: &Path, out: &Path, report: &Report) -> Result<Manifest, CliError> {
+    let files = collect_files(root)?;
+
+    let mut entries = Vec::new();
+    for rel in &files {
+        let data = fs::read(root.join(rel))?;
+        entries.push((slash(rel), data));
+    }
+
+    let manifest = Manifest {
+        manifest_version: MANIFEST_VERSION,
+        created_at: rfc3339(SystemTime::now()),
+        cli_version: env!("CARGO_PKG_VERSION").to_string(),
+        git: report.run.git.clone(),
+        files: entries
+            .iter()
+            .map(|(path, data)| ManifestFile {
+                path: path.clone(),
+                size: data.len() as u64,
+                sha256: sha256_hex(data),
+            })
+            .collect(),
+        report: report.clone(),
+    };
+    let manifest_json = serde_json::to_vec_pretty(&manifest)?;
+
+    write_atomically(out, |file| {
+        let mut tar = tar::Builder::new(GzEncoder::new(file, Compression::default()));
+        append(&mut tar, MANIFEST, &manifest_json, 0o644)?;
+        for (path, data) in &entries {
+            let mode = if is_executable(&root.join(path)) {
+                0o755
+            } else {
+                0o644
+            };
+            append(&mut tar, path, data, mode)?;
+        }
+        tar.into_inner()?.finish()?.sync_all()?;
+        Ok(())
+    })?;
+
+    Ok(manifest)
+}
+
+/// Writes through a temporary file next to `out` and renames it into place, so a failure never
+/// leaves a truncated file at `out` and readers only ever see a complete one.
+pub fn write_atomically(
+    out: &Path,
+    write: impl FnOnce(File) -> Result<(), CliError>,
+) -> Result<(), CliError> {
+    let name = out
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let tmp = out.with_file_name(format!(".{name}.{}.tmp", std::process::id()));
+    let result = File::create(&tmp)
+        .map_err(CliError::from)
+        .and_then(write)
+        .and_then(|()| fs::rename(&tmp, out).map_err(CliError::from));
+    if result.is_err() {
+        let _ = fs::remove_file(&tmp);
+    }
+    result
+}
+
+// Fixed owner and mtime so the same inputs produce the same archive.
+fn append<W: Write>(
+    tar: &mut tar::Builder<W>,
+    path: &str,
+    data: &[u8],
+    mode: u32,
+) -> Result<(), CliError> {
+    let mut header = tar::Header::new_gnu();
+    header.set_size(data.len() as u64);
+    header.set_mode(mode);
+    header.set_uid(0);
+    header.set_gid(0);
+    header.set_mtime(0);
+    header.set_entry_type(tar::EntryType::Regular);
+    tar.append_data(&mut header, path, data)?;
+    Ok(())
+}
+
+#[cfg(unix)]
+fn is_executable(p: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    fs::metadata(p)
+        .map(|m| m.permissions().mode() & 0o111 != 0)
+        .unwrap_or(false)
+}
+
+#[cfg(not(unix))]
+fn is_executable(_: &Path) -> bool {
+    false
+}
+
+/// Checks every file in `archive` against the checksums in its manifest.
+pub fn verify(archive: &Path) -> Result<Verification, CliError> {
+    let file = File::open(archive)?;
+    // Anything past opening the file is a gzip or tar decoding error: the input is not a bundle.
+    let (manifest_raw, mut actual) = read_entries(file).map_err(|e| {
+        CliError::Bundle(format!(
+            "{} is not a readable .tar.gz bundle: {e}",
+            archive.display()
+        ))
+    })?;
+
+    let raw = manifest_raw
+        .ok_or_else(|| CliError::Bundle(format!("{} has no {MANIFEST}", archive.display())))?;
+    let manifest: Manifest = serde_json::from_slice(&raw)
+        .map_err(|e| CliError::Bundle(format!("invalid {MANIFEST}: {e}")))?;
+
+    let mut problems = Vec::new();
+    if manifest.manifest_version > MANIFEST_VERSION {
+        problems.push(format!(
+            "manifest version {} is newer than this CLI supports ({MANIFEST_VERSION})",
+            manifest.manifest_version
+        ));
+    }
+    for f in &manifest.files {
+        match actual.remove(&f.path) {
+            None => problems.push(format!("{}: missing from the archive", f.path)),
+            Some((size, _)) if size != f.size => problems.push(format!(
+                "{}: size {size} does not match the manifest ({})",
+                f.path, f.size
+            )),
+            Some((_, sha)) if sha != f.sha256 => {
+                problems.push(format!("{}: SHA-256 does not match the manifest", f.path))
+            }
+            Some(_) => {}
+        }
+    }
+    for path in actual.keys() {
+        problems.push(format!("{path}: not listed in the manifest"));
+    }
+    let failed = manifest.report.counts.get("fail").copied().unwrap_or(0);
+    if failed > 0 {
+        problems.push(format!(
+            "embedded readiness report has {failed} failing check(s)"
+        ));
+    }
+
+    Ok(Verification { manifest, problems })
+}
+
+// The manifest bytes, if present, and the size and SHA-256 of every other file.
+type Entries = (Option<Vec<u8>>, BTreeMap<String, (u64, String)>);
+
+fn read_entries(file: File) -> io::Result<Entries> {
+    let mut tar = tar::Archive::new(GzDecoder::new(file));
+    let mut manifest_raw = None;
+    let mut actual = BTreeMap::new();
+    for entry in tar.entries()? {
+        let mut entry = entry?;
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+        // Archives repacked by hand usually carry a leading `./`.
+        let path = slash(&entry.path()?).trim_start_matches("./").to_string();
+        let mut data = Vec::new();
+        entry.read_to_end(&mut data)?;
+        if path == MANIFEST {
+            manifest_raw = Some(data);
+        } else {
+            actual.insert(path, (data.len() as u64, sha256_hex(&data)));
+        }
+    }
+    Ok((manifest_raw, actual))
+}
+
+fn sha256_hex(data: &[u8]) -> String {
+    Sha256::digest(data)
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scratch(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("bundle-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn report() -> Report {
+        Report {
+            schema_version: 1,
+            root: ".".to_string(),
+            profile: "prod".to_string(),
+            run: Default::default(),
+            counts: BTreeMap::new(),
+            findings: Vec::new(),
+        }
+    }
+
+    // A repo with one file in each deployable directory, and its bundle.
+    fn bundled(name: &str) -> (PathBuf, PathBuf) {
+        let dir = scratch(name);
+        let root = dir.join("repo");
+        for (path, text) in [
+            ("apps/be/dist/main.js", "console.log(1)\n"),
+            ("apps/fe/dist/index.html", "<html></html>\n"),
+            ("apps/be/prisma/schema.prisma", "datasource db {}\n"),
+            ("apps/be/prisma/dev.db", "local"),
+            ("ecosystem.config.js", "module.exports = {}\n"),
+            ("nginx/site.conf", "server {}\n"),
+        ] {
+            let path = root.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, text).unwrap();
+        }
+        let out = dir.join("release.tar.gz");
+        create(&root, &out, &report()).unwrap();
+        (dir, out)
+    }
+
+    // Rewrites `archive` to `out`, letting `edit` change or drop (by returning false) each entry.
+    fn repack(archive: &Path, out: &Path, edit: impl Fn(&str, &mut Vec<u8>) -> bool) {
+        let mut input = tar::Archive::new(GzDecoder::new(File::open(archive).unwrap()));
+        let file = File::create(out).unwrap();
+        let mut tar = tar::Builder::new(GzEncoder::new(file, Compression::default()));
+        for entry in input.entries().unwrap() {
+            let mut entry = entry.unwrap();
+            let path = slash(&entry.path().unwrap());
+            let mut data = Vec::new();
+            entry.read_to_end(&mut data).unwrap();
+            if edit(&path, &mut data) {
+                append(&mut tar, &path, &data, 0o644).unwrap();
+            }
+        }
+        tar.into_inner().unwrap().finish().unwrap();
+    }
+
+    #[test]
+    fn created_bundle_verifies() {
+        let (dir, out) = bundled("roundtrip");
+        let v = verify(&out).unwrap();
+        assert!(v.is_ok(), "{:?}", v.problems);
+        let paths: Vec<&str> = v.manifest.files.iter().map(|f| f.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            [
+                "apps/be/dist/main.js",
+                "apps/be/prisma/schema.prisma",
+                "apps/fe/dist/index.html",
+                "ecosystem.config.js",
+                "nginx/site.conf"
+            ]
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn changed_byte_fails_verification() {
+        let (dir, out) = bundled("tamper");
+        let tampered = dir.join("tampered.tar.gz");
+        repack(&out, &tampered, |path, data| {
+            if path == "apps/be/dist/main.js" {
+                data[0] ^= 1;
+            }
+            true
+        });
+        let v = verify(&tampered).unwrap();
+        assert_eq!(
+            v.problems,
+            ["apps/be/dist/main.js: SHA-256 does not match the manifest"]
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn missing_manifest_is_rejected() {
+        let (dir, out) = bundled("no-manifest");
+        let stripped = dir.join("stripped.tar.gz");
+        repack(&out, &stripped, |path, _| path != MANIFEST);
+        let err = verify(&stripped).unwrap_err();
+        assert!(matches!(&err, CliError::Bundle(m) if m.contains("has no manifest.json")));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn non_gzip_input_is_a_bundle_error() {
+        let dir = scratch("not-gzip");
+        let path = dir.join("release.tar.gz");
+        fs::write(&path, "not an archive").unwrap();
+        let err = verify(&path).unwrap_err();
+        assert!(matches!(err, CliError::Bundle(_)), "{err:?}");
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn failed_write_leaves_nothing_behind() {
+        let dir = scratch("failed");
+        let out = dir.join("release.tar.gz");
+        let result = write_atomically(&out, |mut file| {
+            file.write_all(b"partial")?;
+            Err(CliError::Bundle("boom".to_string()))
+        });
+        assert!(result.is_err());
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 0);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn failed_write_keeps_the_previous_archive() {
+        let dir = scratch("previous");
+        let out = dir.join("release.tar.gz");
+        fs::write(&out, "old").unwrap();
+        let _ = write_atomically(&out, |_| Err(CliError::Bundle("boom".to_string())));
+        assert_eq!(fs::read_to_string(&out).unwrap(), "old");
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn successful_write_replaces_the_archive() {
+        let dir = scratch("ok");
+        let out = dir.join("release.tar.gz");
+        fs::write(&out, "old").unwrap();
+        write_atomically(&out, |mut file| Ok(file.write_all(b"new")?)).unwrap();
+        assert_eq!(fs::read_to_string(&out).unwrap(), "new");
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
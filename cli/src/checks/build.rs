@@ -1,6 +1,28 @@
-use crate::report::{finding, Finding, Status};
+use super::FindingDoc;
+use crate::report::{Finding, Status, finding};
 use std::path::Path;
 
+pub const DOCS: &str = "README.md#quality";
+
+pub const FINDINGS: &[FindingDoc] = &[
+    FindingDoc {
+        id: "build.be_dist",
+        summary: "Backend bundle apps/be/dist/index.js exists",
+        why: "pm2 and the deploy scripts start the compiled bundle, not the TypeScript sources.",
+        inspects: "Whether `apps/be/dist/index.js` exists.",
+        fix: "Run `bun run build:be` from the repo root.",
+        example: None,
+    },
+    FindingDoc {
+        id: "build.fe_dist",
+        summary: "Frontend build apps/fe/dist exists",
+        why: "nginx serves the static frontend from the Vite output directory.",
+        inspects: "Whether `apps/fe/dist` is a directory.",
+        fix: "Run `bun run build:fe` from the repo root.",
+        example: None,
+    },
+];
+
 pub fn check(root: &Path) -> Vec<Finding> {
     let mut out = Vec::new();
 
@@ -10,6 +32,7 @@ pub fn check(root: &Path) -> Vec<Finding> {
         "Backend build output exists (apps/be/dist/index.js)",
         &be_dist,
         Status::Warn,
+        "Run: bun run build:be",
     ));
 
     let fe_dist = root.join("apps/fe/dist");
@@ -18,35 +41,28 @@ pub fn check(root: &Path) -> Vec<Finding> {
         "Frontend build output exists (apps/fe/dist)",
         &fe_dist,
         Status::Warn,
+        "Run: bun run build:fe",
     ));
 
     out
 }
 
-fn path_exists(id: &str, title: &str, p: &Path, missing_status: Status) -> Finding {
+fn path_exists(id: &str, title: &str, p: &Path, missing_status: Status, fix: &str) -> Finding {
     if p.exists() {
-        let mut f = finding(id, title, Status::Ok, "Found");
-        f.meta
-            .insert("path".to_string(), p.to_string_lossy().to_string());
-        f
+        finding(id, title, Status::Ok, "Found").at(p, None)
     } else {
-        let mut f = finding(id, title, missing_status, "Not found");
-        f.meta
-            .insert("path".to_string(), p.to_string_lossy().to_string());
-        f
+        finding(id, title, missing_status, "Not found")
+            .at(p, None)
+            .fix(fix)
     }
 }
 
-fn dir_exists(id: &str, title: &str, p: &Path, missing_status: Status) -> Finding {
+fn dir_exists(id: &str, title: &str, p: &Path, missing_status: Status, fix: &str) -> Finding {
     if p.is_dir() {
-        let mut f = finding(id, title, Status::Ok, "Found");
-        f.meta
-            .insert("path".to_string(), p.to_string_lossy().to_string());
-        f
+        finding(id, title, Status::Ok, "Found").at(p, None)
     } else {
-        let mut f = finding(id, title, missing_status, "Not found");
-        f.meta
-            .insert("path".to_string(), p.to_string_lossy().to_string());
-        f
+        finding(id, title, missing_status, "Not found")
+            .at(p, None)
+            .fix(fix)
     }
 }
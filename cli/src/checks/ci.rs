@@ -1,6 +1,30 @@
-use crate::report::{finding, Finding, Status};
+use super::FindingDoc;
+use crate::report::{Finding, Status, finding};
 use std::path::Path;
 
+pub const DOCS: &str = "docs/contributing.md#ci";
+
+pub const FINDINGS: &[FindingDoc] = &[FindingDoc {
+    id: "ci.workflow",
+    summary: "A GitHub Actions CI workflow exists",
+    why: "CI runs format, lint, typecheck and tests on every pull request so broken changes do not reach main.",
+    inspects: "Whether `.github/workflows/ci.yml` exists.",
+    fix: "Add a workflow that installs with bun and runs the repository scripts.",
+    example: Some(
+        r#"on:
+  pull_request:
+jobs:
+  build:
+    runs-on: ubuntu-latest
+    steps:
+      - uses: actions/checkout@v4
+      - uses: oven-sh/setup-bun@v2
+      - run: bun install
+      - run: bun run lint
+      - run: bun run typecheck"#,
+    ),
+}];
+
 pub fn check(root: &Path) -> Vec<Finding> {
     let mut out = Vec::new();
 
@@ -10,21 +34,18 @@ pub fn check(root: &Path) -> Vec<Finding> {
         "GitHub Actions workflow exists (.github/workflows/ci.yml)",
         &ci,
         Status::Warn,
+        "Add a workflow that runs lint, typecheck and tests on pull requests",
     ));
 
     out
 }
 
-fn path_exists(id: &str, title: &str, p: &Path, missing_status: Status) -> Finding {
+fn path_exists(id: &str, title: &str, p: &Path, missing_status: Status, fix: &str) -> Finding {
     if p.exists() {
-        let mut f = finding(id, title, Status::Ok, "Found");
-        f.meta
-            .insert("path".to_string(), p.to_string_lossy().to_string());
-        f
+        finding(id, title, Status::Ok, "Found").at(p, None)
     } else {
-        let mut f = finding(id, title, missing_status, "Not found");
-        f.meta
-            .insert("path".to_string(), p.to_string_lossy().to_string());
-        f
+        finding(id, title, missing_status, "Not found")
+            .at(p, None)
+            .fix(fix)
     }
 }
@@ -0,0 +1,381 @@
+use super::FindingDoc;
+use crate::report::{Finding, Status, finding, unreadable};
+use crate::util::env::{key_line, parse_env};
+use crate::util::file::read_optional;
+use crate::util::path::rel_str;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+pub const DOCS: &str = "docs/architecture.md#environment--configuration";
+
+pub const FINDINGS: &[FindingDoc] = &[
+    FindingDoc {
+        id: "cors.localhost_origin",
+        summary: "ALLOWED_ORIGINS has no localhost origins",
+        why: "The backend falls back to localhost origins when ALLOWED_ORIGINS is unset, which breaks the deployed frontend and trusts whatever runs on a visitor's machine.",
+        inspects: "`ALLOWED_ORIGINS` in the profile's env file, or the defaults from apps/be/src/app.ts when unset. An empty value allows no origin at all and fails too.",
+        fix: "List only the production frontend origins.",
+        example: Some("ALLOWED_ORIGINS=https://app.example.com,https://admin.example.com"),
+    },
+    FindingDoc {
+        id: "cors.insecure_origin",
+        summary: "Allowed origins use https",
+        why: "Credentialed requests from an http:// origin can be read or modified in transit.",
+        inspects: "Non-local `http://` entries in `ALLOWED_ORIGINS`.",
+        fix: "Serve the frontend over TLS and list its https:// origin.",
+        example: None,
+    },
+    FindingDoc {
+        id: "cors.wildcard_credentials",
+        summary: "No wildcard origin with credentials",
+        why: "The backend enables `credentials: true`; browsers reject credentialed responses for `*`, and reflecting any origin would expose cookies to every site.",
+        inspects: "A `*` entry in `ALLOWED_ORIGINS` while apps/be/src/app.ts sets `credentials: true`.",
+        fix: "Replace `*` with an explicit origin list.",
+        example: None,
+    },
+    FindingDoc {
+        id: "cors.cookie_secure",
+        summary: "Cookies are marked Secure",
+        why: "Auth cookies without Secure are sent over plain HTTP and can be intercepted.",
+        inspects: "`COOKIE_SECURE`, falling back to `NODE_ENV=production` like the backend does.",
+        fix: "Set `COOKIE_SECURE=true` for deployments behind HTTPS.",
+        example: Some("NODE_ENV=production\nCOOKIE_SECURE=true\nCOOKIE_SAMESITE=lax"),
+    },
+    FindingDoc {
+        id: "cors.samesite_none",
+        summary: "SameSite=None cookies are also Secure",
+        why: "Browsers drop `SameSite=None` cookies that are not Secure, so logins silently fail.",
+        inspects: "`COOKIE_SAMESITE` together with the resolved `COOKIE_SECURE`.",
+        fix: "Set `COOKIE_SECURE=true`, or use `COOKIE_SAMESITE=lax` when cross-site cookies are not needed.",
+        example: None,
+    },
+];
+
+// Mirrors the fallbacks in apps/be/src/app.ts and apps/be/src/config/env.ts.
+const DEFAULT_ORIGINS: [&str; 2] = ["http://localhost:3000", "http://localhost:8000"];
+
+pub fn check(root: &Path, env_file: &Path) -> Vec<Finding> {
+    // These audits are part of the production security posture, alongside the `security` target.
+    findings(root, env_file)
+        .into_iter()
+        .map(|f| f.category("security"))
+        .collect()
+}
+
+fn findings(root: &Path, env_file: &Path) -> Vec<Finding> {
+    let mut out = Vec::new();
+
+    // Every finding here depends on the env file, so a missing or unreadable one stops the check.
+    let raw = match read_optional(env_file) {
+        Ok(Some(raw)) => raw,
+        Ok(None) => {
+            return vec![
+                finding(
+                    "cors.localhost_origin",
+                    "ALLOWED_ORIGINS has no localhost origins",
+                    Status::Warn,
+                    format!(
+                        "{} not found; CORS and cookie settings cannot be checked",
+                        rel_str(root, env_file)
+                    ),
+                )
+                .at(env_file, None)
+                .fix("Create the env file (see env.be) and rerun the check"),
+            ];
+        }
+        Err(e) => {
+            return vec![unreadable(
+                "cors.localhost_origin",
+                "ALLOWED_ORIGINS has no localhost origins",
+                env_file,
+                &e,
+            )];
+        }
+    };
+    let env = parse_env(&raw);
+    let app_path = root.join("apps/be/src/app.ts");
+    let app = match read_optional(&app_path) {
+        Ok(app) => app.unwrap_or_default(),
+        Err(e) => {
+            return vec![unreadable(
+                "cors.wildcard_credentials",
+                "Wildcard CORS origin is not combined with credentials",
+                &app_path,
+                &e,
+            )];
+        }
+    };
+    let credentials_line = app
+        .lines()
+        .position(|l| l.contains("credentials: true"))
+        .map(|i| i + 1);
+    let at_key = |f: Finding, key: &str| f.at(env_file, key_line(&raw, key));
+
+    // Like `ALLOWED_ORIGINS?.split(",") ||` in app.ts: only an unset variable falls back, and an
+    // empty one yields the single origin "", which matches no request.
+    let (origins, from_default) = match env.get("ALLOWED_ORIGINS") {
+        Some(v) => (
+            v.split(',')
+                .map(|s| s.trim().to_string())
+                .collect::<Vec<_>>(),
+            false,
+        ),
+        None => (
+            DEFAULT_ORIGINS.iter().map(|s| s.to_string()).collect(),
+            true,
+        ),
+    };
+
+    let localhost: Vec<&String> = origins.iter().filter(|o| is_local_origin(o)).collect();
+    let title = "ALLOWED_ORIGINS has no localhost origins";
+    out.push(at_key(
+        if origins.iter().all(|o| o.is_empty()) {
+            finding(
+                "cors.localhost_origin",
+                title,
+                Status::Fail,
+                "ALLOWED_ORIGINS is empty, so app.ts allows no cross-origin requests",
+            )
+            .fix("Set ALLOWED_ORIGINS to the production frontend origin(s), e.g. https://app.example.com")
+        } else if localhost.is_empty() {
+            finding(
+                "cors.localhost_origin",
+                title,
+                Status::Ok,
+                "No localhost origins",
+            )
+        } else if from_default {
+            finding(
+                "cors.localhost_origin",
+                title,
+                Status::Fail,
+                "ALLOWED_ORIGINS is unset, so app.ts falls back to localhost origins",
+            )
+            .fix("Set ALLOWED_ORIGINS to the production frontend origin(s), e.g. https://app.example.com")
+        } else {
+            finding(
+                "cors.localhost_origin",
+                title,
+                Status::Fail,
+                format!("Localhost origin(s): {}", join(&localhost)),
+            )
+            .fix("Remove localhost entries from ALLOWED_ORIGINS for production deployments")
+        },
+        "ALLOWED_ORIGINS",
+    ));
+
+    let insecure: Vec<&String> = origins
+        .iter()
+        .filter(|o| o.starts_with("http://") && !is_local_origin(o))
+        .collect();
+    let title = "ALLOWED_ORIGINS uses https origins";
+    out.push(at_key(
+        if insecure.is_empty() {
+            finding(
+                "cors.insecure_origin",
+                title,
+                Status::Ok,
+                "No plain http:// origins",
+            )
+        } else {
+            finding(
+                "cors.insecure_origin",
+                title,
+                Status::Fail,
+                format!("Plain http:// origin(s): {}", join(&insecure)),
+            )
+            .fix("Serve the frontend over TLS and list its https:// origin instead")
+        },
+        "ALLOWED_ORIGINS",
+    ));
+
+    let wildcard = origins.iter().any(|o| o == "*");
+    let title = "Wildcard CORS origin is not combined with credentials";
+    out.push(if wildcard && credentials_line.is_some() {
+        finding(
+            "cors.wildcard_credentials",
+            title,
+            Status::Fail,
+            "ALLOWED_ORIGINS contains * while app.ts enables credentials: true",
+        )
+        .at(&app_path, credentials_line)
+        .fix("Replace * with an explicit origin list; browsers reject credentialed wildcard responses")
+        .docs("https://developer.mozilla.org/en-US/docs/Web/HTTP/Guides/CORS#credentialed_requests_and_wildcards")
+    } else {
+        at_key(
+            finding(
+                "cors.wildcard_credentials",
+                title,
+                Status::Ok,
+                if wildcard {
+                    "Wildcard origin without credentials"
+                } else {
+                    "No wildcard origin"
+                },
+            ),
+            "ALLOWED_ORIGINS",
+        )
+    });
+
+    let secure = cookie_secure(&env);
+    let title = "Cookies are marked Secure";
+    out.push(at_key(
+        if secure {
+            finding(
+                "cors.cookie_secure",
+                title,
+                Status::Ok,
+                "COOKIE_SECURE resolves to true",
+            )
+        } else {
+            finding(
+                "cors.cookie_secure",
+                title,
+                Status::Fail,
+                match env.get("COOKIE_SECURE") {
+                    Some(v) => format!("COOKIE_SECURE={v}"),
+                    None => "COOKIE_SECURE is unset and NODE_ENV is not production".to_string(),
+                },
+            )
+            .fix("Set COOKIE_SECURE=true (or NODE_ENV=production) for deployments behind HTTPS")
+        },
+        "COOKIE_SECURE",
+    ));
+
+    let same_site = cookie_same_site(&env);
+    let title = "SameSite=None cookies are also Secure";
+    out.push(at_key(
+        if same_site == "none" && !secure {
+            finding(
+                "cors.samesite_none",
+                title,
+                Status::Fail,
+                "COOKIE_SAMESITE resolves to none while COOKIE_SECURE is false; browsers drop these cookies",
+            )
+            .fix("Set COOKIE_SECURE=true, or COOKIE_SAMESITE=lax if cross-site cookies are not needed")
+            .docs("https://developer.mozilla.org/en-US/docs/Web/HTTP/Reference/Headers/Set-Cookie#samesitesamesite-value")
+        } else {
+            finding(
+                "cors.samesite_none",
+                title,
+                Status::Ok,
+                format!("SameSite={same_site}, Secure={secure}"),
+            )
+        },
+        "COOKIE_SAMESITE",
+    ));
+
+    out
+}
+
+fn join(items: &[&String]) -> String {
+    items
+        .iter()
+        .map(|s| s.as_str())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn is_production(env: &BTreeMap<String, String>) -> bool {
+    env.get("NODE_ENV").map(|v| v.as_str()) == Some("production")
+}
+
+fn cookie_secure(env: &BTreeMap<String, String>) -> bool {
+    match env.get("COOKIE_SECURE").map(|v| v.as_str()) {
+        Some("true") | Some("1") => true,
+        Some("false") | Some("0") => false,
+        _ => is_production(env),
+    }
+}
+
+fn cookie_same_site(env: &BTreeMap<String, String>) -> String {
+    match env.get("COOKIE_SAMESITE") {
+        Some(v) => v.to_lowercase(),
+        None if is_production(env) => "none".to_string(),
+        None => "lax".to_string(),
+    }
+}
+
+fn is_local_origin(origin: &str) -> bool {
+    let host = origin
+        .split("://")
+        .nth(1)
+        .unwrap_or(origin)
+        .split('/')
+        .next()
+        .unwrap_or_default();
+    let host = match host.strip_prefix('[') {
+        Some(v6) => v6.split(']').next().unwrap_or_default(),
+        None => host.split(':').next().unwrap_or_default(),
+    };
+    matches!(host, "localhost" | "127.0.0.1" | "0.0.0.0" | "::1") || host.ends_with(".localhost")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn run(name: &str, env: Option<&str>) -> Vec<Finding> {
+        let root = std::env::temp_dir().join(format!("cors-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("apps/be")).unwrap();
+        let env_file = root.join("apps/be/.env.production");
+        if let Some(env) = env {
+            fs::write(&env_file, env).unwrap();
+        }
+        let found = check(&root, &env_file);
+        fs::remove_dir_all(&root).unwrap();
+        found
+    }
+
+    #[test]
+    fn allowed_origins_follow_app_ts() {
+        let status = |env: &str| {
+            let found = run("origins", Some(env));
+            let f = found
+                .iter()
+                .find(|f| f.id == "cors.localhost_origin")
+                .unwrap();
+            (f.status, f.details.clone())
+        };
+        assert_eq!(
+            status("NODE_ENV=production\n"),
+            (
+                Status::Fail,
+                "ALLOWED_ORIGINS is unset, so app.ts falls back to localhost origins".to_string()
+            )
+        );
+        // `"".split(",")` is `[""]`, which is truthy: no fallback, and no origin is allowed.
+        assert_eq!(
+            status("ALLOWED_ORIGINS=\n"),
+            (
+                Status::Fail,
+                "ALLOWED_ORIGINS is empty, so app.ts allows no cross-origin requests".to_string()
+            )
+        );
+        assert_eq!(
+            status("ALLOWED_ORIGINS=https://app.example.com,http://localhost:3000\n"),
+            (
+                Status::Fail,
+                "Localhost origin(s): http://localhost:3000".to_string()
+            )
+        );
+        assert_eq!(
+            status("ALLOWED_ORIGINS=https://app.example.com\n").0,
+            Status::Ok
+        );
+    }
+
+    #[test]
+    fn missing_env_file_is_reported_as_such() {
+        let found = run("missing", None);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].status, Status::Warn);
+        assert_eq!(
+            found[0].details,
+            "apps/be/.env.production not found; CORS and cookie settings cannot be checked"
+        );
+        assert_eq!(found[0].category, "security");
+    }
+}
@@ -0,0 +1,285 @@
+use super::FindingDoc;
+use crate::config::{CheckKind, CustomCheck};
+use crate::report::{Finding, Status, finding, unreadable};
+use crate::util::command::command_exists;
+use crate::util::file::read_optional;
+use regex::Regex;
+use serde_json::Value;
+use std::path::Path;
+use std::process::Command;
+
+pub const DOCS: &str = "README.md#cli";
+
+// Custom ids come from the config file, so there is nothing to list statically.
+pub const FINDINGS: &[FindingDoc] = &[];
+
+pub fn check(root: &Path, checks: &[CustomCheck]) -> Vec<Finding> {
+    checks.iter().map(|c| run(root, c)).collect()
+}
+
+// What the check inspects, for `check --list` and `explain`.
+pub fn describe(c: &CustomCheck) -> String {
+    let path = c.path.as_deref().unwrap_or_default();
+    let pattern = c.pattern.as_deref().unwrap_or_default();
+    let command = command_line(c);
+    let key = c.key.as_deref().unwrap_or_default();
+    let value = c.value.as_ref().map(Value::to_string).unwrap_or_default();
+    match c.kind {
+        CheckKind::FileExists => format!("File {path} exists"),
+        CheckKind::DirExists => format!("Directory {path} exists"),
+        CheckKind::FileMatchesRegex => format!("{path} matches /{pattern}/"),
+        CheckKind::CommandExists => format!("{command} is on PATH"),
+        CheckKind::CommandOutputMatches => format!("Output of `{command}` matches /{pattern}/"),
+        CheckKind::JsonPathEquals | CheckKind::YamlPathEquals | CheckKind::TomlPathEquals => {
+            format!("{key} in {path} equals {value}")
+        }
+    }
+}
+
+fn command_line(c: &CustomCheck) -> String {
+    let mut parts = vec![c.command.clone().unwrap_or_default()];
+    parts.extend(c.args.iter().cloned());
+    parts.join(" ")
+}
+
+fn run(root: &Path, c: &CustomCheck) -> Finding {
+    // A broken rule cannot pass, whatever severity it asks for.
+    if let Err(e) = c.validate() {
+        return finding(&c.id, &c.title, Status::Fail, format!("Invalid rule: {e}"))
+            .category("custom")
+            .meta("kind", c.kind.name())
+            .fix("Fix the rule's fields; the config loader reports the same error");
+    }
+    let path = c.path.as_deref().map(|p| root.join(p));
+    // Kinds that inspect a file read it here, so an unreadable file fails instead of looking missing.
+    let reads_file = matches!(
+        c.kind,
+        CheckKind::FileMatchesRegex
+            | CheckKind::JsonPathEquals
+            | CheckKind::YamlPathEquals
+            | CheckKind::TomlPathEquals
+    );
+    let text = match &path {
+        Some(p) if reads_file => match read_optional(p) {
+            Ok(text) => text,
+            Err(e) => {
+                return unreadable(&c.id, &c.title, p, &e)
+                    .category("custom")
+                    .meta("kind", c.kind.name());
+            }
+        },
+        _ => None,
+    };
+    let (passed, details, line) = match c.kind {
+        CheckKind::FileExists => exists(path.as_deref(), Path::is_file),
+        CheckKind::DirExists => exists(path.as_deref(), Path::is_dir),
+        CheckKind::FileMatchesRegex => file_matches(text.as_deref(), c),
+        CheckKind::CommandExists => {
+            let cmd = c.command.as_deref().unwrap_or_default();
+            if command_exists(cmd) {
+                (true, format!("{cmd} command found"), None)
+            } else {
+                (false, format!("{cmd} command not found"), None)
+            }
+        }
+        CheckKind::CommandOutputMatches => command_matches(root, c),
+        CheckKind::JsonPathEquals | CheckKind::YamlPathEquals | CheckKind::TomlPathEquals => {
+            path_equals(text.as_deref(), c)
+        }
+    };
+
+    let status = if passed { Status::Ok } else { c.severity };
+    let mut f = finding(&c.id, &c.title, status, details)
+        .category("custom")
+        .meta("kind", c.kind.name())
+        .meta("rule", describe(c));
+    if let Some(p) = &path {
+        f = f.at(p, line);
+    }
+    if !passed && let Some(fix) = &c.fix {
+        f = f.fix(fix);
+    }
+    f
+}
+
+fn exists(path: Option<&Path>, test: fn(&Path) -> bool) -> (bool, String, Option<usize>) {
+    match path {
+        Some(p) if test(p) => (true, "Found".to_string(), None),
+        _ => (false, "Not found".to_string(), None),
+    }
+}
+
+fn file_matches(text: Option<&str>, c: &CustomCheck) -> (bool, String, Option<usize>) {
+    let Some(text) = text else {
+        return (false, "Not found".to_string(), None);
+    };
+    let pattern = c.pattern.as_deref().unwrap_or_default();
+    let re = match Regex::new(pattern) {
+        Ok(re) => re,
+        Err(e) => return (false, format!("Invalid rule: invalid pattern: {e}"), None),
+    };
+    match re.find(text) {
+        Some(m) => {
+            let line = text[..m.start()].matches('\n').count() + 1;
+            (
+                true,
+                format!("/{pattern}/ matched at line {line}"),
+                Some(line),
+            )
+        }
+        None => (false, format!("No match for /{pattern}/"), None),
+    }
+}
+
+fn command_matches(root: &Path, c: &CustomCheck) -> (bool, String, Option<usize>) {
+    let cmd = c.command.as_deref().unwrap_or_default();
+    let output = match Command::new(cmd).args(&c.args).current_dir(root).output() {
+        Ok(o) => o,
+        Err(e) => {
+            return (
+                false,
+                format!("Failed to run `{}`: {e}", command_line(c)),
+                None,
+            );
+        }
+    };
+    let text = format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let pattern = c.pattern.as_deref().unwrap_or_default();
+    let re = match Regex::new(pattern) {
+        Ok(re) => re,
+        Err(e) => return (false, format!("Invalid rule: invalid pattern: {e}"), None),
+    };
+    let first = text.lines().next().unwrap_or_default().trim();
+    if re.is_match(&text) {
+        (true, format!("Output matches /{pattern}/ ({first})"), None)
+    } else {
+        (
+            false,
+            format!("Output does not match /{pattern}/ ({first})"),
+            None,
+        )
+    }
+}
+
+fn path_equals(raw: Option<&str>, c: &CustomCheck) -> (bool, String, Option<usize>) {
+    let Some(raw) = raw else {
+        return (false, "Not found".to_string(), None);
+    };
+    let doc: Result<Value, String> = match c.kind {
+        CheckKind::YamlPathEquals => serde_yaml_ng::from_str(raw).map_err(|e| e.to_string()),
+        CheckKind::TomlPathEquals => toml::from_str(raw).map_err(|e| e.message().to_string()),
+        _ => serde_json::from_str(raw).map_err(|e| e.to_string()),
+    };
+    let doc = match doc {
+        Ok(doc) => doc,
+        Err(e) => return (false, format!("Failed to parse: {e}"), None),
+    };
+
+    let key = c.key.as_deref().unwrap_or_default();
+    let expected = c.value.clone().unwrap_or_default();
+    match lookup(&doc, key) {
+        Some(actual) if actual == &expected => (true, format!("{key} = {actual}"), None),
+        Some(actual) => (
+            false,
+            format!("{key} is {actual}, expected {expected}"),
+            None,
+        ),
+        None => (false, format!("{key} is not set"), None),
+    }
+}
+
+fn lookup<'a>(doc: &'a Value, key: &str) -> Option<&'a Value> {
+    key.split('.').try_fold(doc, |v, seg| match v {
+        Value::Array(items) => seg.parse::<usize>().ok().and_then(|i| items.get(i)),
+        _ => v.get(seg),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(kind: CheckKind, path: &str, pattern: &str) -> CustomCheck {
+        CustomCheck {
+            id: "custom.test".to_string(),
+            title: "test rule".to_string(),
+            kind,
+            severity: Status::Warn,
+            fix: None,
+            path: Some(path.to_string()),
+            pattern: Some(pattern.to_string()),
+            command: Some("true".to_string()),
+            args: Vec::new(),
+            key: None,
+            value: None,
+        }
+    }
+
+    #[test]
+    fn invalid_pattern_fails_instead_of_panicking() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        for kind in [CheckKind::FileMatchesRegex, CheckKind::CommandOutputMatches] {
+            let found = check(root, &[rule(kind, "Cargo.toml", "(unclosed")]);
+            assert_eq!(found.len(), 1);
+            assert_eq!(found[0].status, Status::Fail);
+            assert!(
+                found[0]
+                    .details
+                    .starts_with("Invalid rule: invalid pattern"),
+                "{}",
+                found[0].details
+            );
+        }
+    }
+
+    #[test]
+    fn unreadable_file_fails_instead_of_looking_missing() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        // A directory in place of the file cannot be read as text.
+        let found = check(root, &[rule(CheckKind::FileMatchesRegex, "src", "x")]);
+        assert_eq!(found[0].status, Status::Fail);
+        assert!(
+            found[0].details.starts_with("Cannot read"),
+            "{}",
+            found[0].details
+        );
+        assert_eq!(found[0].category, "custom");
+
+        let mut r = rule(CheckKind::JsonPathEquals, "missing.json", "x");
+        r.key = Some("a".to_string());
+        r.value = Some(Value::Bool(true));
+        let found = check(root, &[r]);
+        assert_eq!(
+            (found[0].status, found[0].details.as_str()),
+            (Status::Warn, "Not found")
+        );
+    }
+
+    #[test]
+    fn missing_fields_fail() {
+        let mut r = rule(CheckKind::FileMatchesRegex, "Cargo.toml", "x");
+        r.pattern = None;
+        let found = check(Path::new("."), &[r]);
+        assert_eq!(found[0].status, Status::Fail);
+        assert!(found[0].details.contains("missing `pattern`"));
+    }
+
+    #[test]
+    fn valid_pattern_matches() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let found = check(
+            root,
+            &[rule(
+                CheckKind::FileMatchesRegex,
+                "Cargo.toml",
+                r"^\[package\]",
+            )],
+        );
+        assert_eq!(found[0].status, Status::Ok);
+        assert_eq!(found[0].location.as_ref().and_then(|l| l.line), Some(1));
+    }
+}
@@ -0,0 +1,795 @@
+use super::{FindingDoc, Problem, listed, summarize};
+use crate::report::{Finding, Status, finding, invalid, unreadable};
+use crate::util::dockerfile::{DockerIgnore, Instruction, Vars, parse};
+use crate::util::env::backend_ports;
+use crate::util::path::rel_str;
+use crate::util::repo::BACKEND_PROCESS_TYPES;
+use crate::util::walk::walk_files;
+use regex::Regex;
+use serde_yaml_ng::Value;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+pub const DOCS: &str = "docs/architecture.md#running-different-process-types";
+
+pub const FINDINGS: &[FindingDoc] = &[
+    FindingDoc {
+        id: "docker.files",
+        summary: "Dockerfiles or a compose file are versioned in the repo",
+        why: "The other docker findings review these files; without them there is nothing to check.",
+        inspects: "Files named `Dockerfile`, `Dockerfile.*` or `*.Dockerfile`, and `compose.yaml`/`compose.yml`/`docker-compose.yml`.",
+        fix: "Add a Dockerfile for the backend and a compose.yaml that starts each process type.",
+        example: None,
+    },
+    FindingDoc {
+        id: "docker.bun_version",
+        summary: "oven/bun base images pin the bun version CI uses",
+        why: "A floating tag such as `latest` or `1` changes the runtime under a deploy; a version other than CI's runs code that was never tested on it.",
+        inspects: "`FROM oven/bun:<tag>` (after `ARG` expansion) against `bun-version` in .github/workflows/*.yml.",
+        fix: "Pin the full version CI uses, e.g. `FROM oven/bun:1.3.5-slim`.",
+        example: Some("ARG BUN_VERSION=1.3.5\nFROM oven/bun:${BUN_VERSION}-slim"),
+    },
+    FindingDoc {
+        id: "docker.env_copy",
+        summary: "No .env file is copied into an image",
+        why: "Anything copied into an image ships with every push of it; env files hold database URLs and secrets that belong in the runtime environment.",
+        inspects: "`COPY`/`ADD` sources naming a `.env` file, and directory copies (such as `COPY . .`) whose `.env` files the .dockerignore does not exclude.",
+        fix: "Remove the copy and pass configuration with `env_file:`/`environment:` in compose or `docker run --env-file`.",
+        example: None,
+    },
+    FindingDoc {
+        id: "docker.ports",
+        summary: "Exposed and published ports match PORT/ADMIN_PORT",
+        why: "The main server listens on PORT and the admin server on ADMIN_PORT; any other container port has nothing behind it.",
+        inspects: "`EXPOSE` in Dockerfiles and the container side of compose `ports:`/`expose:` against the profile's env file, `.env.example` or the 8000/8001 defaults. Worker services should not publish ports.",
+        fix: "Expose only PORT for main and ADMIN_PORT for admin.",
+        example: Some("EXPOSE 8000 8001"),
+    },
+    FindingDoc {
+        id: "docker.dockerignore",
+        summary: ".dockerignore excludes node_modules, .env and target",
+        why: "Sending node_modules and Rust target directories makes builds slow and platform-dependent; .env files leak secrets into the build context.",
+        inspects: "`<Dockerfile>.dockerignore` or the root .dockerignore, matched against node_modules, apps/*/node_modules, .env, apps/be/.env and the Rust target directories.",
+        fix: "Add `**/node_modules`, `**/.env` and `**/target` to .dockerignore.",
+        example: Some("**/node_modules\n**/.env\n**/.env.*\n!**/.env.example\n**/target\n.git"),
+    },
+    FindingDoc {
+        id: "docker.compose_services",
+        summary: "compose defines a service per backend process type",
+        why: "The backend runs as `main`, `admin` and `worker`; a compose file without one of them deploys part of the app.",
+        inspects: "`PROCESS_TYPE` in the `environment` of each compose service.",
+        fix: "Add one service per PROCESS_TYPE, all built from the same image.",
+        example: Some(
+            "services:\n  main:\n    build: .\n    environment:\n      PROCESS_TYPE: main\n    ports: [\"8000:8000\"]\n  admin:\n    build: .\n    environment:\n      PROCESS_TYPE: admin\n    ports: [\"8001:8001\"]\n  worker:\n    build: .\n    environment:\n      PROCESS_TYPE: worker",
+        ),
+    },
+];
+
+const COMPOSE_FILES: [&str; 4] = [
+    "compose.yaml",
+    "compose.yml",
+    "docker-compose.yaml",
+    "docker-compose.yml",
+];
+
+// Paths that must stay out of the build context, grouped by the name reported.
+const IGNORED: [(&str, &[&str]); 3] = [
+    (
+        "node_modules",
+        &[
+            "node_modules",
+            "apps/be/node_modules",
+            "apps/fe/node_modules",
+        ],
+    ),
+    (".env", &[".env", "apps/be/.env", "apps/be/.env.production"]),
+    ("target", &["cli/target", "gui-edit/src-tauri/target"]),
+];
+
+// Env files that a directory copy would pick up.
+const ENV_FILES: [&str; 3] = [".env", "apps/be/.env", "apps/be/.env.production"];
+
+struct Dockerfile {
+    path: PathBuf,
+    rel: String,
+    instructions: Vec<Instruction>,
+}
+
+struct Compose {
+    path: PathBuf,
+    rel: String,
+    text: String,
+    services: Vec<Service>,
+}
+
+struct Service {
+    name: String,
+    line: Option<usize>,
+    process_type: Option<String>,
+    port_env: Option<String>,
+    // Container-side ports from `ports:` and `expose:`.
+    ports: Vec<String>,
+}
+
+pub fn check(root: &Path, env_file: &Path) -> Vec<Finding> {
+    let title = "Dockerfile or compose file exists in repo";
+    let files = walk_files(root, 20_000);
+    let mut dockerfiles = Vec::new();
+    let mut composes = Vec::new();
+    // Files that exist but cannot be read or parsed; the other findings skip them.
+    let mut broken = Vec::new();
+    for p in &files {
+        let is_compose = p
+            .file_name()
+            .is_some_and(|n| COMPOSE_FILES.contains(&n.to_string_lossy().as_ref()));
+        if !is_compose && !is_dockerfile(p) {
+            continue;
+        }
+        let text = match fs::read_to_string(p) {
+            Ok(text) => text,
+            Err(e) => {
+                broken.push(unreadable("docker.files", title, p, &e));
+                continue;
+            }
+        };
+        if !is_compose {
+            dockerfiles.push(Dockerfile {
+                path: p.clone(),
+                rel: rel_str(root, p),
+                instructions: parse(&text),
+            });
+            continue;
+        }
+        match services(&text) {
+            Ok(services) => composes.push(Compose {
+                path: p.clone(),
+                rel: rel_str(root, p),
+                services,
+                text,
+            }),
+            Err(e) => {
+                let line = e.location().map(|l| l.line());
+                broken.push(invalid("docker.files", title, p, line, e));
+            }
+        }
+    }
+    dockerfiles.sort_by(|a, b| a.rel.cmp(&b.rel));
+    composes.sort_by(|a, b| a.rel.cmp(&b.rel));
+
+    if dockerfiles.is_empty() && composes.is_empty() {
+        if !broken.is_empty() {
+            return broken;
+        }
+        return vec![
+            finding(
+                "docker.files",
+                title,
+                Status::Warn,
+                "No Dockerfile or compose.yaml found",
+            )
+            .fix("Add a Dockerfile for the backend and a compose.yaml with a service per PROCESS_TYPE"),
+        ];
+    }
+
+    let names: Vec<String> = dockerfiles
+        .iter()
+        .map(|d| d.rel.clone())
+        .chain(composes.iter().map(|c| c.rel.clone()))
+        .collect();
+    let ports = backend_ports(root, env_file);
+    let mut out = broken;
+    if out.is_empty() {
+        out.push(finding(
+            "docker.files",
+            title,
+            Status::Ok,
+            format!("Found {}", listed(&names)),
+        ));
+    }
+    out.extend([
+        check_bun_version(root, &dockerfiles),
+        check_env_copy(root, &dockerfiles),
+        check_ports(&dockerfiles, &composes, ports),
+        check_dockerignore(root, &dockerfiles),
+        check_compose_services(root, &composes),
+    ]);
+    out
+}
+
+fn is_dockerfile(p: &Path) -> bool {
+    let name = p
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    name == "Dockerfile"
+        || (name.starts_with("Dockerfile.") && !name.ends_with(".dockerignore"))
+        || name.ends_with(".Dockerfile")
+        || name.ends_with(".dockerfile")
+}
+
+static BUN_VERSION: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"bun-version:\s*["']?([0-9][^"'\s]*)"#).expect("valid regex"));
+
+// `bun-version` from the CI workflows, with where it was found.
+fn ci_bun_version(root: &Path) -> Option<(String, String)> {
+    let mut workflows: Vec<PathBuf> = fs::read_dir(root.join(".github/workflows"))
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|e| e == "yml" || e == "yaml"))
+        .collect();
+    workflows.sort();
+    workflows.iter().find_map(|p| {
+        let text = fs::read_to_string(p).ok()?;
+        let version = BUN_VERSION.captures(&text)?.get(1)?.as_str().to_string();
+        Some((version, rel_str(root, p)))
+    })
+}
+
+fn check_bun_version(root: &Path, dockerfiles: &[Dockerfile]) -> Finding {
+    let ci = ci_bun_version(root);
+    let mut problems = Vec::new();
+    let mut images = 0;
+
+    for d in dockerfiles {
+        let mut vars = Vars::default();
+        for ins in &d.instructions {
+            if ins.keyword != "FROM" {
+                vars.record(ins);
+                continue;
+            }
+            let image = ins
+                .args
+                .split_whitespace()
+                .find(|w| !w.starts_with("--"))
+                .map(|w| vars.expand(w))
+                .unwrap_or_default();
+            let Some(reference) = image
+                .strip_prefix("docker.io/")
+                .unwrap_or(&image)
+                .strip_prefix("oven/bun")
+            else {
+                continue;
+            };
+            images += 1;
+            let (reference, digest) = match reference.split_once('@') {
+                Some((r, _)) => (r, true),
+                None => (reference, false),
+            };
+            let tag = reference.strip_prefix(':').unwrap_or("");
+            let version = tag.split('-').next().unwrap_or("");
+            let pinned = version.split('.').count() >= 3
+                && version.split('.').all(|p| p.parse::<u32>().is_ok());
+
+            if !pinned && !digest {
+                let shown = if tag.is_empty() { "latest" } else { tag };
+                problems.push(Problem::new(
+                    &d.path,
+                    &d.rel,
+                    Some(ins.line),
+                    format!("oven/bun:{shown} is not pinned to a full version"),
+                ));
+            } else if let Some((ci_version, workflow)) = &ci
+                && pinned
+                && version != ci_version
+            {
+                problems.push(Problem::new(
+                    &d.path,
+                    &d.rel,
+                    Some(ins.line),
+                    format!("oven/bun:{tag} but {workflow} uses bun {ci_version}"),
+                ));
+            }
+        }
+    }
+
+    let ok = match &ci {
+        Some((v, _)) => format!("{images} oven/bun image(s) match CI bun {v}"),
+        None => format!("{images} oven/bun image(s) pinned"),
+    };
+    summarize(
+        "docker.bun_version",
+        "Docker base images pin the bun version used in CI",
+        Status::Warn,
+        ok,
+        problems,
+        "Pin FROM oven/bun:<version> to the bun-version in .github/workflows",
+    )
+}
+
+fn dockerignore_for(root: &Path, d: &Dockerfile) -> Option<DockerIgnore> {
+    // BuildKit prefers `<Dockerfile>.dockerignore` next to the Dockerfile.
+    let specific = PathBuf::from(format!("{}.dockerignore", d.path.display()));
+    fs::read_to_string(&specific)
+        .or_else(|_| fs::read_to_string(root.join(".dockerignore")))
+        .ok()
+        .map(|t| DockerIgnore::parse(&t))
+}
+
+fn check_env_copy(root: &Path, dockerfiles: &[Dockerfile]) -> Finding {
+    let mut problems = Vec::new();
+    for d in dockerfiles {
+        let ignore = dockerignore_for(root, d).unwrap_or_default();
+        for ins in d
+            .instructions
+            .iter()
+            .filter(|i| i.keyword == "COPY" || i.keyword == "ADD")
+        {
+            // Copies from another stage or image never read the build context.
+            let words: Vec<&str> = ins.args.split_whitespace().collect();
+            if words.iter().any(|w| w.starts_with("--from=")) {
+                continue;
+            }
+            let paths: Vec<&str> = words
+                .iter()
+                .filter(|w| !w.starts_with("--"))
+                .copied()
+                .collect();
+            let Some((_, sources)) = paths.split_last() else {
+                continue;
+            };
+            for src in sources {
+                let src = src
+                    .trim_matches(|c| c == '"' || c == '[' || c == ']' || c == ',')
+                    .trim_start_matches("./")
+                    .trim_end_matches('/');
+                let name = src.rsplit('/').next().unwrap_or(src);
+                if name.starts_with(".env") && name != ".env.example" {
+                    problems.push(Problem::new(
+                        &d.path,
+                        &d.rel,
+                        Some(ins.line),
+                        format!("{} copies {src} into the image", ins.keyword),
+                    ));
+                    continue;
+                }
+                let dir = if src.is_empty() || src == "." {
+                    ""
+                } else {
+                    src
+                };
+                let leaked: Vec<&str> = ENV_FILES
+                    .iter()
+                    .copied()
+                    .filter(|f| dir.is_empty() || f.starts_with(&format!("{dir}/")))
+                    .filter(|f| root.join(f).is_file() && !ignore.excludes(f))
+                    .collect();
+                if !leaked.is_empty() {
+                    problems.push(Problem::new(
+                        &d.path,
+                        &d.rel,
+                        Some(ins.line),
+                        format!(
+                            "{} {} includes {} (not excluded by .dockerignore)",
+                            ins.keyword,
+                            if dir.is_empty() { "." } else { dir },
+                            leaked.join(", ")
+                        ),
+                    ));
+                }
+            }
+        }
+    }
+
+    summarize(
+        "docker.env_copy",
+        "No .env file is copied into a Docker image",
+        Status::Fail,
+        format!("{} Dockerfile(s) keep env files out", dockerfiles.len()),
+        problems,
+        "Pass configuration at runtime (env_file:/environment: in compose) and add **/.env to .dockerignore",
+    )
+}
+
+fn container_port(spec: &str) -> Option<String> {
+    let spec = spec.trim().trim_matches('"');
+    let port = spec.rsplit(':').next()?.split('/').next()?;
+    (!port.is_empty()).then(|| port.to_string())
+}
+
+fn check_ports(dockerfiles: &[Dockerfile], composes: &[Compose], ports: (u16, u16)) -> Finding {
+    let (port, admin_port) = ports;
+    let allowed: BTreeSet<String> = [port.to_string(), admin_port.to_string()].into();
+    let mut problems = Vec::new();
+
+    for d in dockerfiles {
+        let mut vars = Vars::default();
+        for ins in &d.instructions {
+            if ins.keyword != "EXPOSE" {
+                vars.record(ins);
+                continue;
+            }
+            for word in ins.args.split_whitespace() {
+                let expanded = vars.expand(word);
+                let Some(p) = container_port(&expanded) else {
+                    continue;
+                };
+                if p.contains('$') {
+                    continue;
+                }
+                if !allowed.contains(&p) {
+                    problems.push(Problem::new(
+                        &d.path,
+                        &d.rel,
+                        Some(ins.line),
+                        format!("EXPOSE {p} is neither PORT={port} nor ADMIN_PORT={admin_port}"),
+                    ));
+                }
+            }
+        }
+    }
+
+    for c in composes {
+        for s in &c.services {
+            let expected = match s.process_type.as_deref() {
+                Some("main") => s.port_env.clone().unwrap_or_else(|| port.to_string()),
+                Some("admin") => s.port_env.clone().unwrap_or_else(|| admin_port.to_string()),
+                Some("worker") => {
+                    if let Some(p) = s.ports.first() {
+                        let line = line_after(&c.text, s.line, p);
+                        problems.push(Problem::new(
+                            &c.path,
+                            &c.rel,
+                            line,
+                            format!("worker service {} publishes port {p}", s.name),
+                        ));
+                    }
+                    continue;
+                }
+                _ => continue,
+            };
+            for p in s.ports.iter().filter(|p| **p != expected) {
+                let line = line_after(&c.text, s.line, p);
+                problems.push(Problem::new(
+                    &c.path,
+                    &c.rel,
+                    line,
+                    format!(
+                        "service {} exposes container port {p}, expected {expected}",
+                        s.name
+                    ),
+                ));
+            }
+        }
+    }
+
+    summarize(
+        "docker.ports",
+        "Docker exposes the PORT and ADMIN_PORT the backend listens on",
+        Status::Warn,
+        format!("PORT={port}, ADMIN_PORT={admin_port}"),
+        problems,
+        "Expose PORT for the main service and ADMIN_PORT for the admin service",
+    )
+}
+
+fn check_dockerignore(root: &Path, dockerfiles: &[Dockerfile]) -> Finding {
+    let mut problems = Vec::new();
+    for d in dockerfiles {
+        let Some(ignore) = dockerignore_for(root, d) else {
+            problems.push(Problem::new(
+                &d.path,
+                &d.rel,
+                None,
+                "has no .dockerignore (root or next to the Dockerfile)".to_string(),
+            ));
+            continue;
+        };
+        let missing: Vec<&str> = IGNORED
+            .iter()
+            .filter(|(_, paths)| paths.iter().any(|p| !ignore.excludes(p)))
+            .map(|(name, _)| *name)
+            .collect();
+        if !missing.is_empty() {
+            problems.push(Problem::new(
+                &d.path,
+                &d.rel,
+                None,
+                format!(".dockerignore does not exclude {}", missing.join(", ")),
+            ));
+        }
+    }
+
+    summarize(
+        "docker.dockerignore",
+        ".dockerignore excludes node_modules, .env and target",
+        Status::Warn,
+        "node_modules, .env and target stay out of the build context".to_string(),
+        problems,
+        "Add **/node_modules, **/.env and **/target to .dockerignore",
+    )
+}
+
+fn check_compose_services(root: &Path, composes: &[Compose]) -> Finding {
+    let title = "compose defines a service per backend process type";
+    if composes.is_empty() {
+        return finding(
+            "docker.compose_services",
+            title,
+            Status::Warn,
+            "No compose.yaml found",
+        )
+        .at(root.join("compose.yaml"), None)
+        .fix("Add compose.yaml with one service per PROCESS_TYPE");
+    }
+
+    let mut problems = Vec::new();
+    for c in composes {
+        let mut by_type: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+        for s in &c.services {
+            if let Some(ty) = s.process_type.as_deref() {
+                by_type.entry(ty).or_default().push(&s.name);
+                if !BACKEND_PROCESS_TYPES.contains(&ty) {
+                    let line = line_after(&c.text, s.line, "PROCESS_TYPE");
+                    problems.push(Problem::new(
+                        &c.path,
+                        &c.rel,
+                        line,
+                        format!("service {} has unknown PROCESS_TYPE={ty}", s.name),
+                    ));
+                }
+            }
+        }
+        let missing: Vec<&str> = BACKEND_PROCESS_TYPES
+            .iter()
+            .copied()
+            .filter(|t| !by_type.contains_key(t))
+            .collect();
+        if !missing.is_empty() {
+            problems.push(Problem::new(
+                &c.path,
+                &c.rel,
+                line_after(&c.text, None, "services:"),
+                format!("has no service with PROCESS_TYPE={}", missing.join("/")),
+            ));
+        }
+    }
+
+    summarize(
+        "docker.compose_services",
+        title,
+        Status::Warn,
+        "main, admin and worker each have a service".to_string(),
+        problems,
+        "Add one compose service per PROCESS_TYPE (main, admin, worker)",
+    )
+}
+
+fn services(text: &str) -> Result<Vec<Service>, serde_yaml_ng::Error> {
+    let doc: Value = serde_yaml_ng::from_str(text)?;
+    let Some(map) = doc.get("services").and_then(Value::as_mapping) else {
+        return Ok(Vec::new());
+    };
+
+    let services_line = line_after(text, None, "services:");
+    let mut out = Vec::new();
+    for (name, svc) in map {
+        let Some(name) = name.as_str() else {
+            continue;
+        };
+        let env = environment(svc.get("environment"));
+        let process_type = env.get("PROCESS_TYPE").cloned();
+        let port_env = match process_type.as_deref() {
+            Some("admin") => env.get("ADMIN_PORT").cloned(),
+            _ => env.get("PORT").cloned(),
+        };
+        let mut ports = Vec::new();
+        for key in ["ports", "expose"] {
+            for p in svc
+                .get(key)
+                .and_then(Value::as_sequence)
+                .into_iter()
+                .flatten()
+            {
+                let spec = match p {
+                    Value::String(s) => container_port(s),
+                    Value::Number(n) => Some(n.to_string()),
+                    Value::Mapping(_) => p.get("target").map(|t| match t {
+                        Value::String(s) => s.clone(),
+                        other => serde_yaml_ng::to_string(other)
+                            .unwrap_or_default()
+                            .trim()
+                            .to_string(),
+                    }),
+                    _ => None,
+                };
+                ports.extend(spec);
+            }
+        }
+        out.push(Service {
+            line: line_after(text, services_line, &format!("{name}:")),
+            name: name.to_string(),
+            process_type,
+            port_env,
+            ports,
+        });
+    }
+    Ok(out)
+}
+
+// Compose accepts `environment` as a map or a list of `KEY=value`.
+fn environment(value: Option<&Value>) -> BTreeMap<String, String> {
+    let mut out = BTreeMap::new();
+    match value {
+        Some(Value::Mapping(m)) => {
+            for (k, v) in m {
+                let Some(k) = k.as_str() else {
+                    continue;
+                };
+                let v = match v {
+                    Value::String(s) => s.clone(),
+                    Value::Null => continue,
+                    other => serde_yaml_ng::to_string(other)
+                        .unwrap_or_default()
+                        .trim()
+                        .to_string(),
+                };
+                out.insert(k.to_string(), v);
+            }
+        }
+        Some(Value::Sequence(items)) => {
+            for item in items.iter().filter_map(Value::as_str) {
+                if let Some((k, v)) = item.split_once('=') {
+                    out.insert(k.to_string(), v.to_string());
+                }
+            }
+        }
+        _ => {}
+    }
+    out
+}
+
+// First line at or after `from` containing `needle`; YAML values do not keep positions.
+fn line_after(text: &str, from: Option<usize>, needle: &str) -> Option<usize> {
+    let start = from.unwrap_or(1);
+    text.lines()
+        .enumerate()
+        .skip(start - 1)
+        .find(|(_, l)| l.contains(needle))
+        .map(|(i, _)| i + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tree(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("docker-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        for (path, text) in files {
+            let path = root.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, text).unwrap();
+        }
+        root
+    }
+
+    #[test]
+    fn malformed_compose_is_reported() {
+        let root = tree(
+            "malformed",
+            &[
+                ("Dockerfile", "FROM oven/bun:1.1.38\n"),
+                ("compose.yaml", "services:\n  main:\n    image: [app\n"),
+            ],
+        );
+        let found = check(&root, &root.join(".env"));
+        let f = found.iter().find(|f| f.id == "docker.files").unwrap();
+        assert_eq!(f.status, Status::Fail);
+        assert!(f.details.starts_with("Cannot parse"), "{}", f.details);
+        assert!(f.location.as_ref().unwrap().path.ends_with("compose.yaml"));
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    fn dockerfile(text: &str) -> Dockerfile {
+        Dockerfile {
+            path: PathBuf::from("Dockerfile"),
+            rel: "Dockerfile".to_string(),
+            instructions: parse(text),
+        }
+    }
+
+    #[test]
+    fn base_images_are_compared_with_ci() {
+        let root = tree(
+            "bun-version",
+            &[(
+                ".github/workflows/ci.yml",
+                "steps:\n  - uses: oven-sh/setup-bun@v2\n    with:\n      bun-version: \"1.1.38\"\n",
+            )],
+        );
+        let f = check_bun_version(
+            &root,
+            &[
+                dockerfile(
+                    "FROM oven/bun:1.1.38-alpine AS build\nFROM docker.io/oven/bun:1.1.38\n",
+                ),
+                dockerfile("FROM oven/bun@sha256:abc\n"),
+                dockerfile("FROM node:22\n"),
+            ],
+        );
+        assert_eq!(f.status, Status::Ok, "{}", f.details);
+        assert_eq!(f.details, "3 oven/bun image(s) match CI bun 1.1.38");
+
+        let f = check_bun_version(
+            &root,
+            &[
+                dockerfile("FROM oven/bun\n"),
+                dockerfile("FROM oven/bun:1.1\n"),
+                dockerfile("ARG BUN=1.1.30\nFROM --platform=linux/amd64 oven/bun:${BUN}-slim\n"),
+            ],
+        );
+        assert_eq!(f.status, Status::Warn);
+        assert_eq!(
+            f.details,
+            "Dockerfile:1 oven/bun:latest is not pinned to a full version, \
+             Dockerfile:1 oven/bun:1.1 is not pinned to a full version, \
+             Dockerfile:2 oven/bun:1.1.30-slim but .github/workflows/ci.yml uses bun 1.1.38"
+        );
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn compose_services_are_parsed() {
+        let text = "\
+services:
+  main:
+    build: .
+    environment:
+      PROCESS_TYPE: main
+      PORT: 8000
+    ports:
+      - \"8000:8000\"
+      - 127.0.0.1:9229:9229/tcp
+  admin:
+    build: .
+    environment:
+      - PROCESS_TYPE=admin
+      - ADMIN_PORT=8001
+    expose: [8001]
+    ports:
+      - target: 8002
+        published: 80
+  worker:
+    build: .
+";
+        let parsed = services(text).unwrap();
+        let summary: Vec<_> = parsed
+            .iter()
+            .map(|s| {
+                (
+                    s.name.as_str(),
+                    s.line,
+                    s.process_type.as_deref(),
+                    s.port_env.as_deref(),
+                    s.ports.clone(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                (
+                    "main",
+                    Some(2),
+                    Some("main"),
+                    Some("8000"),
+                    vec!["8000".to_string(), "9229".to_string()]
+                ),
+                (
+                    "admin",
+                    Some(10),
+                    Some("admin"),
+                    Some("8001"),
+                    vec!["8002".to_string(), "8001".to_string()]
+                ),
+                ("worker", Some(19), None, None, Vec::new()),
+            ]
+        );
+        assert!(services("version: \"3\"\n").unwrap().is_empty());
+        assert!(services("services: [\n").is_err());
+    }
+}
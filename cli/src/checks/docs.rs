@@ -0,0 +1,325 @@
+use super::{FindingDoc, listed};
+use crate::report::{Finding, Status, finding, unreadable};
+use crate::util::file::read_optional;
+use crate::util::markdown::{Heading, anchors, headings, links};
+use crate::util::path::slash;
+use crate::util::walk::walk_files;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const DOCS: &str = "docs/contributing.md";
+
+pub const FINDINGS: &[FindingDoc] = &[
+    FindingDoc {
+        id: "docs.translations",
+        summary: "Every English doc has a Korean translation and vice versa",
+        why: "The repo keeps docs/X.md and docs/X.ko.md (and README.md/README.ko.md) side by side; a missing half leaves one audience without the page.",
+        inspects: "Markdown files under docs/ and the root README, paired by the `.ko.md` suffix.",
+        fix: "Add the missing translation, or remove the orphaned one.",
+        example: None,
+    },
+    FindingDoc {
+        id: "docs.headings",
+        summary: "Translated pairs have the same heading structure",
+        why: "A section added to one language but not the other is the usual way translations drift.",
+        inspects: "The sequence of heading levels (outside code blocks) of each pair; the first divergence is reported with both lines.",
+        fix: "Add or remove the section in the other language so the outlines match.",
+        example: None,
+    },
+    FindingDoc {
+        id: "docs.links",
+        summary: "Relative links and anchors in markdown resolve",
+        why: "Broken links in docs are found by readers, usually at the worst time.",
+        inspects: "Inline `[text](target)` links in every markdown file: relative paths must exist and `#anchors` must match a heading (GitHub slug rules). External URLs are not fetched.",
+        fix: "Fix the path or anchor; anchors are the heading text lowercased with spaces turned into hyphens.",
+        example: Some("[Process types](./architecture.md#backend-process-types)"),
+    },
+];
+
+pub fn check(root: &Path) -> Vec<Finding> {
+    let mut files: Vec<PathBuf> = walk_files(root, 20_000)
+        .into_iter()
+        .filter(|p| p.extension().is_some_and(|e| e == "md"))
+        .filter_map(|p| p.strip_prefix(root).ok().map(Path::to_path_buf))
+        .collect();
+    files.sort();
+
+    let translated: Vec<&PathBuf> = files
+        .iter()
+        .filter(|p| {
+            p.starts_with("docs") || p == &Path::new("README.md") || p == &Path::new("README.ko.md")
+        })
+        .collect();
+
+    vec![
+        check_translations(root, &translated),
+        check_headings(root, &translated),
+        check_links(root, &files),
+    ]
+}
+
+// `docs/x.md` <-> `docs/x.ko.md`.
+fn counterpart(p: &Path) -> PathBuf {
+    let s = slash(p);
+    match s.strip_suffix(".ko.md") {
+        Some(base) => PathBuf::from(format!("{base}.md")),
+        None => PathBuf::from(format!("{}.ko.md", s.trim_end_matches(".md"))),
+    }
+}
+
+fn check_translations(root: &Path, files: &[&PathBuf]) -> Finding {
+    let title = "Every doc has both an English and a Korean version";
+    let missing: Vec<(String, PathBuf)> = files
+        .iter()
+        .map(|p| (p, counterpart(p)))
+        .filter(|(_, other)| !root.join(other).is_file())
+        .map(|(p, other)| (slash(&other), (*p).clone()))
+        .collect();
+
+    match missing.first() {
+        None => finding(
+            "docs.translations",
+            title,
+            Status::Ok,
+            format!(
+                "{} translated pair(s)",
+                files
+                    .iter()
+                    .filter(|p| !slash(p).ends_with(".ko.md"))
+                    .count()
+            ),
+        ),
+        Some((_, source)) => {
+            let names: Vec<String> = missing.iter().map(|(m, _)| m.clone()).collect();
+            finding(
+                "docs.translations",
+                title,
+                Status::Warn,
+                format!("Missing translation(s): {}", listed(&names)),
+            )
+            .at(root.join(source), None)
+            .fix("Add the missing translation next to its counterpart")
+            .meta("count", missing.len().to_string())
+        }
+    }
+}
+
+fn check_headings(root: &Path, files: &[&PathBuf]) -> Finding {
+    let title = "Translated docs have the same heading structure";
+    let mut drift = Vec::new();
+    let mut first = None;
+    let mut pairs = 0;
+
+    for en in files.iter().filter(|p| !slash(p).ends_with(".ko.md")) {
+        let ko = counterpart(en);
+        // A missing half is reported by docs.translations.
+        let (en_path, ko_path) = (root.join(en), root.join(&ko));
+        let (en_text, ko_text) = match (read_optional(&en_path), read_optional(&ko_path)) {
+            (Ok(Some(a)), Ok(Some(b))) => (a, b),
+            (Err(e), _) => return unreadable("docs.headings", title, &en_path, &e),
+            (_, Err(e)) => return unreadable("docs.headings", title, &ko_path, &e),
+            _ => continue,
+        };
+        pairs += 1;
+        let (a, b) = (headings(&en_text), headings(&ko_text));
+        if let Some(d) = divergence(&a, &b) {
+            drift.push(format!(
+                "{}:{} vs {}:{}",
+                slash(en),
+                d.0.map_or_else(
+                    || "end".to_string(),
+                    |h| format!("{} ({})", h.line, outline(h))
+                ),
+                slash(&ko),
+                d.1.map_or_else(
+                    || "end".to_string(),
+                    |h| format!("{} ({})", h.line, outline(h))
+                ),
+            ));
+            first.get_or_insert((root.join(en), d.0.or(a.last()).map(|h| h.line)));
+        }
+    }
+
+    match first {
+        None => finding(
+            "docs.headings",
+            title,
+            Status::Ok,
+            format!("{pairs} pair(s) have matching outlines"),
+        ),
+        Some((path, line)) => finding(
+            "docs.headings",
+            title,
+            Status::Warn,
+            format!("Outlines diverge: {}", listed(&drift)),
+        )
+        .at(path, line)
+        .fix("Add or remove the section in the other language so the outlines match")
+        .meta("count", drift.len().to_string()),
+    }
+}
+
+fn outline(h: &Heading) -> String {
+    format!("{} {}", "#".repeat(h.level), h.text)
+}
+
+// First position where the heading levels differ; `None` on either side means that outline ended.
+fn divergence<'a>(
+    a: &'a [Heading],
+    b: &'a [Heading],
+) -> Option<(Option<&'a Heading>, Option<&'a Heading>)> {
+    let n = a.len().max(b.len());
+    (0..n)
+        .map(|i| (a.get(i), b.get(i)))
+        .find(|(x, y)| x.map(|h| h.level) != y.map(|h| h.level))
+}
+
+fn check_links(root: &Path, files: &[PathBuf]) -> Finding {
+    let title = "Relative links and anchors in markdown resolve";
+    // `None` for a target that exists but cannot be read.
+    let mut anchor_cache: BTreeMap<PathBuf, Option<Vec<String>>> = BTreeMap::new();
+    let mut broken = Vec::new();
+    let mut first = None;
+    let mut checked = 0;
+
+    for rel in files {
+        let path = root.join(rel);
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) => return unreadable("docs.links", title, &path, &e),
+        };
+        for link in links(&text) {
+            let target = link.target.as_str();
+            if target.contains("://") || target.starts_with("mailto:") || target.starts_with("tel:")
+            {
+                continue;
+            }
+            checked += 1;
+            let (file_part, anchor) = match target.split_once('#') {
+                Some((f, a)) => (f, Some(a)),
+                None => (target, None),
+            };
+            let file_part = file_part
+                .split('?')
+                .next()
+                .unwrap_or_default()
+                .replace("%20", " ");
+            let resolved = if file_part.is_empty() {
+                path.clone()
+            } else if let Some(abs) = file_part.strip_prefix('/') {
+                root.join(abs)
+            } else {
+                path.parent().unwrap_or(root).join(&file_part)
+            };
+
+            let problem = if !resolved.exists() {
+                Some("missing file")
+            } else if let Some(anchor) = anchor.filter(|a| !a.is_empty())
+                && resolved.extension().is_some_and(|e| e == "md")
+            {
+                let known = anchor_cache
+                    .entry(resolved.clone())
+                    .or_insert_with(|| fs::read_to_string(&resolved).ok().map(|t| anchors(&t)));
+                match known {
+                    None => Some("unreadable file"),
+                    Some(known) => (!known.iter().any(|k| k == &anchor.to_lowercase()))
+                        .then_some("unknown anchor"),
+                }
+            } else {
+                None
+            };
+
+            if let Some(problem) = problem {
+                broken.push(format!("{}:{} {target} ({problem})", slash(rel), link.line));
+                first.get_or_insert((path.clone(), link.line, link.column));
+            }
+        }
+    }
+
+    match first {
+        None => finding(
+            "docs.links",
+            title,
+            Status::Ok,
+            format!("{checked} relative link(s) resolved"),
+        ),
+        Some((path, line, column)) => finding(
+            "docs.links",
+            title,
+            Status::Warn,
+            format!("Broken link(s): {}", listed(&broken)),
+        )
+        .at(path, Some(line))
+        .at_column(column)
+        .fix("Fix the path or anchor; anchors are heading text lowercased with spaces as hyphens")
+        .meta("count", broken.len().to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tree(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("docs-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        for (path, text) in files {
+            let path = root.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, text).unwrap();
+        }
+        root
+    }
+
+    fn by_id<'a>(found: &'a [Finding], id: &str) -> &'a Finding {
+        found.iter().find(|f| f.id == id).unwrap()
+    }
+
+    #[test]
+    fn broken_links_and_anchors_are_listed() {
+        let root = tree(
+            "links",
+            &[
+                (
+                    "README.md",
+                    "# Readme\n[ok](docs/guide.md#install) [web](https://example.com)\n[gone](docs/gone.md) [bad](docs/guide.md#nope)\n[self](#readme) [spaces](docs/my%20notes.md)\n",
+                ),
+                (
+                    "docs/guide.md",
+                    "# Guide\n## Install\n[up](../README.md#readme)\n",
+                ),
+                ("docs/my notes.md", "notes\n"),
+            ],
+        );
+        let found = check(&root);
+        let f = by_id(&found, "docs.links");
+        assert_eq!(f.status, Status::Warn);
+        assert_eq!(
+            f.details,
+            "Broken link(s): README.md:3 docs/gone.md (missing file), README.md:3 docs/guide.md#nope (unknown anchor)"
+        );
+        let location = f.location.as_ref().unwrap();
+        assert_eq!((location.line, location.column), (Some(3), Some(8)));
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn unreadable_docs_are_reported() {
+        let root = tree(
+            "unreadable",
+            &[("README.md", "# Readme\n"), ("README.ko.md", "# 리드미\n")],
+        );
+        fs::write(root.join("docs.md"), [0xff]).unwrap();
+        let f = check_links(&root, &[PathBuf::from("docs.md")]);
+        assert_eq!(f.status, Status::Fail);
+        assert!(f.details.starts_with("Cannot read"), "{}", f.details);
+
+        fs::write(root.join("README.ko.md"), [0xff]).unwrap();
+        let found = check(&root);
+        let f = by_id(&found, "docs.headings");
+        assert_eq!(f.status, Status::Fail);
+        assert!(f.location.as_ref().unwrap().path.ends_with("README.ko.md"));
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
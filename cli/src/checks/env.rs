@@ -1,66 +1,111 @@
-use crate::report::{finding, Finding, Status};
-use crate::util::env::missing_env_keys;
+use super::FindingDoc;
+use crate::report::{Finding, Status, finding, unreadable};
+use crate::util::env::{key_line, missing_env_keys};
 use std::fs;
 use std::path::Path;
 
-pub fn check(root: &Path) -> Vec<Finding> {
+pub const DOCS: &str = "docs/architecture.md#environment--configuration";
+
+pub const FINDINGS: &[FindingDoc] = &[
+    FindingDoc {
+        id: "env.be_example",
+        summary: "apps/be/.env.example documents the backend variables",
+        why: "The example file is the only list of variables the backend reads; setup, CI and the other env checks rely on it.",
+        inspects: "Whether `apps/be/.env.example` exists.",
+        fix: "Commit `apps/be/.env.example` with every variable and a safe default or placeholder.",
+        example: Some("PORT=8000\nPROCESS_TYPE=main\nDATABASE_URL=\"file:./prisma/dev.db\""),
+    },
+    FindingDoc {
+        id: "env.be",
+        summary: "The profile's backend env file exists",
+        why: "The backend refuses to start, or silently falls back to development defaults, without its env file.",
+        inspects: "`apps/be/.env` for the dev profile, `apps/be/.env.production` for prod, or the profile's `env_file`.",
+        fix: "Copy the example and fill in real values: `cp apps/be/.env.example apps/be/.env`.",
+        example: Some("[profiles.staging]\nextends = \"prod\"\nenv_file = \".env.staging\""),
+    },
+    FindingDoc {
+        id: "env.required_keys",
+        summary: "The env file defines every key from .env.example",
+        why: "A key added to .env.example but not to a deployed env file usually surfaces as a runtime crash or an insecure default.",
+        inspects: "Keys assigned in `.env.example` that are absent from the profile's env file. The location points at the first missing key in `.env.example`.",
+        fix: "Add the listed keys to the env file; `.env.example` shows their defaults.",
+        example: None,
+    },
+];
+
+pub fn check(root: &Path, env_name: &str) -> Vec<Finding> {
     let mut out = Vec::new();
 
     let env_example = root.join("apps/be/.env.example");
-    let env_file = root.join("apps/be/.env");
+    let env_file = root.join("apps/be").join(env_name);
 
     out.push(path_exists(
         "env.be_example",
         "Backend env example exists (apps/be/.env.example)",
         &env_example,
         Status::Fail,
+        "Commit apps/be/.env.example listing every variable the backend reads",
     ));
 
     out.push(path_exists(
         "env.be",
-        "Backend env file exists (apps/be/.env)",
+        &format!("Backend env file exists (apps/be/{env_name})"),
         &env_file,
         Status::Warn,
+        &format!("Run: cp apps/be/.env.example apps/be/{env_name} and fill in real values"),
     ));
 
     if env_example.exists() && env_file.exists() {
-        let example = fs::read_to_string(&env_example).unwrap_or_default();
-        let actual = fs::read_to_string(&env_file).unwrap_or_default();
+        let title = format!("Backend {env_name} contains all keys from .env.example");
+        let (example, actual) = match (
+            fs::read_to_string(&env_example),
+            fs::read_to_string(&env_file),
+        ) {
+            (Ok(example), Ok(actual)) => (example, actual),
+            (Err(e), _) => {
+                out.push(unreadable("env.required_keys", &title, &env_example, &e));
+                return out;
+            }
+            (_, Err(e)) => {
+                out.push(unreadable("env.required_keys", &title, &env_file, &e));
+                return out;
+            }
+        };
         let missing = missing_env_keys(&example, &actual);
 
         if missing.is_empty() {
-            out.push(finding(
-                "env.required_keys",
-                "Backend .env contains all keys from .env.example",
-                Status::Ok,
-                "No missing keys",
-            ));
+            out.push(
+                finding("env.required_keys", &title, Status::Ok, "No missing keys")
+                    .at(&env_file, None),
+            );
         } else {
-            let mut f = finding(
-                "env.required_keys",
-                "Backend .env contains all keys from .env.example",
-                Status::Warn,
-                format!("Missing keys: {}", missing.join(", ")),
+            // Point at the first missing key in .env.example, where its documentation lives.
+            let line = key_line(&example, &missing[0]);
+            out.push(
+                finding(
+                    "env.required_keys",
+                    &title,
+                    Status::Warn,
+                    format!("Missing keys: {}", missing.join(", ")),
+                )
+                .at(&env_example, line)
+                .fix(format!(
+                    "Add the missing keys to apps/be/{env_name} (see .env.example for defaults)"
+                ))
+                .meta("count", missing.len().to_string()),
             );
-            f.meta
-                .insert("count".to_string(), missing.len().to_string());
-            out.push(f);
         }
     }
 
     out
 }
 
-fn path_exists(id: &str, title: &str, p: &Path, missing_status: Status) -> Finding {
+fn path_exists(id: &str, title: &str, p: &Path, missing_status: Status, fix: &str) -> Finding {
     if p.exists() {
-        let mut f = finding(id, title, Status::Ok, "Found");
-        f.meta
-            .insert("path".to_string(), p.to_string_lossy().to_string());
-        f
+        finding(id, title, Status::Ok, "Found").at(p, None)
     } else {
-        let mut f = finding(id, title, missing_status, "Not found");
-        f.meta
-            .insert("path".to_string(), p.to_string_lossy().to_string());
-        f
+        finding(id, title, missing_status, "Not found")
+            .at(p, None)
+            .fix(fix)
     }
 }
@@ -0,0 +1,697 @@
+use super::FindingDoc;
+use crate::report::{Finding, Status, finding, unreadable};
+use crate::util::file::read_optional;
+use crate::util::nginx::directives;
+use crate::util::repo::nginx_config_files;
+use regex::Regex;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+pub const DOCS: &str = "README.md#frontend";
+
+pub const FINDINGS: &[FindingDoc] = &[
+    FindingDoc {
+        id: "fe.index_html",
+        summary: "apps/fe/index.html exists",
+        why: "Vite builds the app from this entry point; the head tags checked below live there.",
+        inspects: "Whether `apps/fe/index.html` exists and is readable.",
+        fix: "Restore apps/fe/index.html from the template.",
+        example: None,
+    },
+    FindingDoc {
+        id: "fe.title",
+        summary: "index.html has a real <title>",
+        why: "The title is shown in tabs, bookmarks and search results; template defaults look unfinished.",
+        inspects: "The `<title>` element, flagging empty titles and template placeholders such as `App` or `Vite + React`.",
+        fix: "Set the product name in the `<title>` of apps/fe/index.html.",
+        example: Some("<title>Bassbook — Practice tracker</title>"),
+    },
+    FindingDoc {
+        id: "fe.meta_description",
+        summary: "index.html has a meta description",
+        why: "Search engines and link previews use it as the page summary.",
+        inspects: "A non-empty `<meta name=\"description\">`.",
+        fix: "Add a one-sentence description to the head of apps/fe/index.html.",
+        example: Some(
+            "<meta name=\"description\" content=\"Track practice sessions and progress.\" />",
+        ),
+    },
+    FindingDoc {
+        id: "fe.viewport",
+        summary: "index.html sets a responsive viewport",
+        why: "Without it mobile browsers render the desktop layout zoomed out.",
+        inspects: "`<meta name=\"viewport\">` containing `width=device-width`.",
+        fix: "Add the standard viewport meta tag.",
+        example: Some(
+            "<meta name=\"viewport\" content=\"width=device-width, initial-scale=1.0\" />",
+        ),
+    },
+    FindingDoc {
+        id: "fe.favicon",
+        summary: "The favicon is declared and present in public/",
+        why: "A missing favicon shows a blank tab icon and fills the access log with 404s.",
+        inspects: "`<link rel=\"icon\">` in index.html and, for root-relative hrefs, the file under apps/fe/public.",
+        fix: "Add the icon file to apps/fe/public or fix the href.",
+        example: Some("<link rel=\"icon\" href=\"/favicon.ico\" />"),
+    },
+    FindingDoc {
+        id: "fe.robots",
+        summary: "robots.txt exists and does not block the whole site",
+        why: "`Disallow: /` left over from staging removes the production site from search engines.",
+        inspects: "apps/fe/public/robots.txt and `Disallow: /` rules in its `User-agent: *` group. Blocking is a failure under the prod profile.",
+        fix: "Allow crawling in production, or keep the file out of the production build.",
+        example: Some("User-agent: *\nDisallow:"),
+    },
+    FindingDoc {
+        id: "fe.dist_assets",
+        summary: "The built index.html references hashed assets that exist",
+        why: "A stale or partial build serves an index.html whose scripts 404; unhashed assets get cached across deploys.",
+        inspects: "Local `src`/`href` references in apps/fe/dist/index.html: that each file exists in dist and that script and stylesheet names carry a content hash.",
+        fix: "Rebuild with `bun run build:fe` and deploy the whole dist directory.",
+        example: None,
+    },
+    FindingDoc {
+        id: "fe.vite_out_dir",
+        summary: "Vite's build.outDir is the directory nginx serves",
+        why: "If the output directory and nginx `root` disagree, deploys keep serving the previous build.",
+        inspects: "`outDir` in apps/fe/vite.config.ts (default `dist`) against `root` directives in the repo's nginx config.",
+        fix: "Point the nginx `root` at apps/fe/<outDir>, or change build.outDir to match.",
+        example: Some(
+            "location / {\n    root /srv/app/apps/fe/dist;\n    try_files $uri /index.html;\n}",
+        ),
+    },
+];
+
+const PLACEHOLDER_TITLES: [&str; 6] = [
+    "app",
+    "vite app",
+    "vite + react",
+    "vite + react + ts",
+    "react app",
+    "my app",
+];
+
+pub fn check(root: &Path) -> Vec<Finding> {
+    let mut out = Vec::new();
+    let fe = root.join("apps/fe");
+
+    let index_path = fe.join("index.html");
+    match read_optional(&index_path) {
+        Ok(Some(html)) => {
+            out.push(
+                finding(
+                    "fe.index_html",
+                    "apps/fe/index.html exists",
+                    Status::Ok,
+                    "Found",
+                )
+                .at(&index_path, None),
+            );
+            out.extend(check_head(&fe, &index_path, &html));
+        }
+        Err(e) => out.push(unreadable(
+            "fe.index_html",
+            "apps/fe/index.html exists",
+            &index_path,
+            &e,
+        )),
+        Ok(None) => out.push(
+            finding(
+                "fe.index_html",
+                "apps/fe/index.html exists",
+                Status::Fail,
+                "Not found",
+            )
+            .at(&index_path, None)
+            .fix("Restore apps/fe/index.html; Vite uses it as the build entry"),
+        ),
+    }
+
+    out.push(check_robots(&fe));
+    out.push(check_dist(&fe));
+    out.push(check_out_dir(root, &fe));
+
+    out
+}
+
+struct Tag {
+    name: String,
+    attrs: Vec<(String, String)>,
+    line: usize,
+}
+
+impl Tag {
+    fn attr(&self, name: &str) -> Option<&str> {
+        self.attrs
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+fn tags(html: &str) -> Vec<Tag> {
+    let tag_re = Regex::new(r"(?s)<([a-zA-Z]+)\s([^>]*)>").expect("valid tag pattern");
+    let attr_re =
+        Regex::new(r#"([a-zA-Z_:][-a-zA-Z0-9_:.]*)\s*=\s*(?:"([^"]*)"|'([^']*)'|([^\s"'>]+))"#)
+            .expect("valid attribute pattern");
+    tag_re
+        .captures_iter(html)
+        .map(|c| {
+            let start = c.get(0).expect("whole match").start();
+            let attrs = attr_re
+                .captures_iter(&c[2])
+                .map(|a| {
+                    let value = a
+                        .get(2)
+                        .or(a.get(3))
+                        .or(a.get(4))
+                        .map_or("", |m| m.as_str());
+                    (a[1].to_string(), value.to_string())
+                })
+                .collect();
+            Tag {
+                name: c[1].to_lowercase(),
+                attrs,
+                line: line_of(html, start),
+            }
+        })
+        .collect()
+}
+
+fn line_of(text: &str, offset: usize) -> usize {
+    text[..offset].matches('\n').count() + 1
+}
+
+fn meta<'a>(tags: &'a [Tag], name: &str) -> Option<&'a Tag> {
+    tags.iter()
+        .find(|t| t.name == "meta" && t.attr("name").is_some_and(|n| n.eq_ignore_ascii_case(name)))
+}
+
+fn check_head(fe: &Path, index_path: &Path, html: &str) -> Vec<Finding> {
+    let mut out = Vec::new();
+    let tags = tags(html);
+
+    let title_re = Regex::new(r"(?is)<title>(.*?)</title>").expect("valid title pattern");
+    let title = "index.html has a descriptive <title>";
+    out.push(match title_re.captures(html) {
+        None => finding("fe.title", title, Status::Warn, "No <title> element")
+            .at(index_path, None)
+            .fix("Add a <title> with the product name to apps/fe/index.html"),
+        Some(c) => {
+            let text = c[1].trim().to_string();
+            let line = line_of(html, c.get(0).expect("whole match").start());
+            let f = if text.is_empty() {
+                finding("fe.title", title, Status::Warn, "<title> is empty")
+                    .fix("Set the product name in the <title> of apps/fe/index.html")
+            } else if PLACEHOLDER_TITLES.contains(&text.to_lowercase().as_str()) {
+                finding(
+                    "fe.title",
+                    title,
+                    Status::Warn,
+                    format!("<title> is the template placeholder \"{text}\""),
+                )
+                .fix("Set the product name in the <title> of apps/fe/index.html")
+            } else {
+                finding(
+                    "fe.title",
+                    title,
+                    Status::Ok,
+                    format!("<title>{text}</title>"),
+                )
+            };
+            f.at(index_path, Some(line))
+        }
+    });
+
+    let title = "index.html has a meta description";
+    out.push(
+        match meta(&tags, "description")
+            .map(|t| (t.attr("content").unwrap_or_default().trim(), t.line))
+        {
+            Some((content, line)) if !content.is_empty() => {
+                finding("fe.meta_description", title, Status::Ok, content)
+                    .at(index_path, Some(line))
+            }
+            Some((_, line)) => finding(
+                "fe.meta_description",
+                title,
+                Status::Warn,
+                "<meta name=\"description\"> is empty",
+            )
+            .at(index_path, Some(line))
+            .fix("Describe the app in one sentence in the description content"),
+            None => finding(
+                "fe.meta_description",
+                title,
+                Status::Warn,
+                "No <meta name=\"description\">",
+            )
+            .at(index_path, None)
+            .fix("Add <meta name=\"description\" content=\"...\" /> to apps/fe/index.html"),
+        },
+    );
+
+    let title = "index.html sets a responsive viewport";
+    out.push(match meta(&tags, "viewport") {
+        Some(t)
+            if t.attr("content")
+                .unwrap_or_default()
+                .contains("width=device-width") =>
+        {
+            finding(
+                "fe.viewport",
+                title,
+                Status::Ok,
+                t.attr("content").unwrap_or_default(),
+            )
+            .at(index_path, Some(t.line))
+        }
+        Some(t) => finding(
+            "fe.viewport",
+            title,
+            Status::Warn,
+            format!(
+                "Viewport does not set width=device-width: {}",
+                t.attr("content").unwrap_or_default()
+            ),
+        )
+        .at(index_path, Some(t.line))
+        .fix("Use content=\"width=device-width, initial-scale=1.0\""),
+        None => finding(
+            "fe.viewport",
+            title,
+            Status::Warn,
+            "No <meta name=\"viewport\">",
+        )
+        .at(index_path, None)
+        .fix("Add <meta name=\"viewport\" content=\"width=device-width, initial-scale=1.0\" />"),
+    });
+
+    let title = "A favicon is declared and exists";
+    let icon = tags.iter().find(|t| {
+        t.name == "link"
+            && t.attr("rel")
+                .is_some_and(|r| r.split_whitespace().any(|w| w.eq_ignore_ascii_case("icon")))
+    });
+    out.push(match icon {
+        None => finding("fe.favicon", title, Status::Warn, "No <link rel=\"icon\">")
+            .at(index_path, None)
+            .fix("Add <link rel=\"icon\" href=\"/favicon.ico\" /> and the icon to apps/fe/public"),
+        Some(t) => {
+            let href = t.attr("href").unwrap_or_default();
+            match public_file(fe, href) {
+                Some(file) if !file.is_file() => finding(
+                    "fe.favicon",
+                    title,
+                    Status::Warn,
+                    format!("{href} is declared but apps/fe/public has no such file"),
+                )
+                .at(index_path, Some(t.line))
+                .fix(format!(
+                    "Add the icon as apps/fe/public/{}",
+                    file.strip_prefix(fe.join("public"))
+                        .unwrap_or(&file)
+                        .display()
+                ))
+                .meta("href", href),
+                _ => finding(
+                    "fe.favicon",
+                    title,
+                    Status::Ok,
+                    format!("Declared as {href}"),
+                )
+                .at(index_path, Some(t.line)),
+            }
+        }
+    });
+
+    out
+}
+
+// File under public/ served at a root-relative href; `None` for external URLs.
+fn public_file(fe: &Path, href: &str) -> Option<PathBuf> {
+    let path = href.split(['?', '#']).next().unwrap_or_default();
+    let rel = path.strip_prefix('/')?;
+    if rel.starts_with('/') || rel.is_empty() {
+        return None;
+    }
+    Some(fe.join("public").join(rel))
+}
+
+fn check_robots(fe: &Path) -> Finding {
+    let path = fe.join("public/robots.txt");
+    let title = "robots.txt exists and allows crawling";
+    let raw = match read_optional(&path) {
+        Ok(Some(raw)) => raw,
+        Ok(None) => {
+            return finding("fe.robots", title, Status::Warn, "Not found")
+                .at(&path, None)
+                .fix("Add apps/fe/public/robots.txt (an empty Disallow: allows everything)");
+        }
+        Err(e) => return unreadable("fe.robots", title, &path, &e),
+    };
+
+    // Rules of the `User-agent: *` group; consecutive User-agent lines share one group.
+    let mut in_star = false;
+    let mut in_agents = false;
+    let mut block = None;
+    for (i, line) in raw.lines().enumerate() {
+        let line = line.split('#').next().unwrap_or_default().trim();
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let (key, value) = (key.trim().to_lowercase(), value.trim());
+        if key == "user-agent" {
+            if !in_agents {
+                in_star = false;
+            }
+            in_star |= value == "*";
+            in_agents = true;
+            continue;
+        }
+        in_agents = false;
+        if in_star && key == "disallow" && value == "/" {
+            block = Some(i + 1);
+            break;
+        }
+    }
+
+    match block {
+        Some(line) => finding(
+            "fe.robots",
+            title,
+            Status::Warn,
+            "User-agent: * is blocked with Disallow: /",
+        )
+        .at(&path, Some(line))
+        .fix("Remove Disallow: / for production deployments"),
+        None => finding("fe.robots", title, Status::Ok, "Crawling is allowed").at(&path, None),
+    }
+}
+
+fn check_dist(fe: &Path) -> Finding {
+    let dist_index = fe.join("dist/index.html");
+    let title = "Built index.html references existing hashed assets";
+    let html = match read_optional(&dist_index) {
+        Ok(Some(html)) => html,
+        Ok(None) => {
+            return finding(
+                "fe.dist_assets",
+                title,
+                Status::Warn,
+                "apps/fe/dist/index.html not found; the frontend has not been built",
+            )
+            .at(&dist_index, None)
+            .fix("Run: bun run build:fe");
+        }
+        Err(e) => return unreadable("fe.dist_assets", title, &dist_index, &e),
+    };
+
+    let hashed = Regex::new(r"[-.][A-Za-z0-9_-]{8,}\.(?:js|mjs|css)$").expect("valid hash pattern");
+    let mut missing = Vec::new();
+    let mut unhashed = Vec::new();
+    let mut first_line = None;
+    let mut checked = 0;
+    for t in tags(&html) {
+        let (attr, bundle) = match t.name.as_str() {
+            "script" => ("src", true),
+            "link" => (
+                "href",
+                t.attr("rel")
+                    .is_some_and(|r| r.contains("stylesheet") || r.contains("modulepreload")),
+            ),
+            _ => continue,
+        };
+        let Some(href) = t.attr(attr) else {
+            continue;
+        };
+        // Query strings and fragments (cache busters) are not part of the file name.
+        let path = href.split(['?', '#']).next().unwrap_or_default();
+        let Some(file) =
+            public_file(&fe.join("dist"), href).map(|_| fe.join("dist").join(&path[1..]))
+        else {
+            continue;
+        };
+        checked += 1;
+        if !file.is_file() {
+            missing.push(href.to_string());
+            first_line.get_or_insert(t.line);
+        } else if bundle && !hashed.is_match(path) {
+            unhashed.push(href.to_string());
+            first_line.get_or_insert(t.line);
+        }
+    }
+
+    if missing.is_empty() && unhashed.is_empty() {
+        return finding(
+            "fe.dist_assets",
+            title,
+            Status::Ok,
+            format!("{checked} local asset reference(s) resolved"),
+        )
+        .at(&dist_index, None);
+    }
+
+    let mut problems = Vec::new();
+    if !missing.is_empty() {
+        problems.push(format!("missing: {}", missing.join(", ")));
+    }
+    if !unhashed.is_empty() {
+        problems.push(format!("not content-hashed: {}", unhashed.join(", ")));
+    }
+    finding(
+        "fe.dist_assets",
+        title,
+        if missing.is_empty() {
+            Status::Warn
+        } else {
+            Status::Fail
+        },
+        format!("Asset problems ({})", problems.join("; ")),
+    )
+    .at(&dist_index, first_line)
+    .fix("Rebuild with: bun run build:fe and deploy the whole dist directory")
+    .meta("missing", missing.len().to_string())
+    .meta("unhashed", unhashed.len().to_string())
+}
+
+fn check_out_dir(root: &Path, fe: &Path) -> Finding {
+    let title = "Vite build.outDir matches the nginx root";
+    let config_path = fe.join("vite.config.ts");
+    let config = match read_optional(&config_path) {
+        Ok(config) => config.unwrap_or_default(),
+        Err(e) => return unreadable("fe.vite_out_dir", title, &config_path, &e),
+    };
+    let out_dir_re =
+        Regex::new(r#"outDir\s*:\s*["'`]([^"'`]+)["'`]"#).expect("valid outDir pattern");
+    let (out_dir, line) = match out_dir_re.captures(&config) {
+        Some(c) => (
+            c[1].to_string(),
+            Some(line_of(&config, c.get(0).expect("whole match").start())),
+        ),
+        None => ("dist".to_string(), None),
+    };
+    let expected = normalize(&fe.join(&out_dir));
+    let expected_rel = expected
+        .strip_prefix(root)
+        .map(Path::to_path_buf)
+        .unwrap_or_else(|_| expected.clone());
+
+    let mut roots = Vec::new();
+    for file in nginx_config_files(root) {
+        let text = match fs::read_to_string(&file) {
+            Ok(text) => text,
+            Err(e) => return unreadable("fe.vite_out_dir", title, &file, &e),
+        };
+        for d in directives(&text, "root") {
+            if let Some(arg) = d.args.first() {
+                roots.push((file.clone(), d.line, arg.clone()));
+            }
+        }
+    }
+
+    let f = if roots.is_empty() {
+        finding(
+            "fe.vite_out_dir",
+            title,
+            Status::Warn,
+            format!(
+                "No nginx root directive found to compare with {}",
+                expected_rel.display()
+            ),
+        )
+        .fix(format!(
+            "Add an nginx config whose root points at {}",
+            expected_rel.display()
+        ))
+    } else {
+        let matched = roots.iter().find(|(_, _, r)| {
+            let served = Path::new(r.trim_end_matches('/'));
+            served == expected || served.ends_with(&expected_rel)
+        });
+        match matched {
+            Some((file, line, r)) => finding(
+                "fe.vite_out_dir",
+                title,
+                Status::Ok,
+                format!("nginx serves {r}"),
+            )
+            .at(file, Some(*line)),
+            None => {
+                let (file, line, _) = &roots[0];
+                let served: Vec<&str> = roots.iter().map(|(_, _, r)| r.as_str()).collect();
+                finding(
+                    "fe.vite_out_dir",
+                    title,
+                    Status::Warn,
+                    format!(
+                        "Vite writes to {} but nginx serves {}",
+                        expected_rel.display(),
+                        served.join(", ")
+                    ),
+                )
+                .at(file, Some(*line))
+                .fix(format!(
+                    "Point the nginx root at {} or change build.outDir",
+                    expected_rel.display()
+                ))
+            }
+        }
+    };
+    let f = f.meta("out_dir", out_dir);
+    match line {
+        Some(line) => f.meta("vite_config", format!("{}:{line}", config_path.display())),
+        None => f,
+    }
+}
+
+// Lexically resolves `.` and `..` without touching the filesystem.
+fn normalize(p: &Path) -> PathBuf {
+    let mut out = PathBuf::new();
+    for c in p.components() {
+        match c {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                out.pop();
+            }
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tree(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("fe-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        for (path, text) in files {
+            let path = root.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, text).unwrap();
+        }
+        root
+    }
+
+    fn by_id<'a>(found: &'a [Finding], id: &str) -> &'a Finding {
+        found.iter().find(|f| f.id == id).unwrap()
+    }
+
+    #[test]
+    fn unreadable_inputs_are_not_reported_as_missing() {
+        let root = tree("unreadable", &[]);
+        // Directories in place of the files make every read fail with something other than NotFound.
+        for dir in ["index.html", "public/robots.txt", "dist/index.html"] {
+            fs::create_dir_all(root.join("apps/fe").join(dir)).unwrap();
+        }
+        let found = check(&root);
+        for id in ["fe.index_html", "fe.robots", "fe.dist_assets"] {
+            let f = by_id(&found, id);
+            assert_eq!(f.status, Status::Fail, "{id}");
+            assert!(f.details.starts_with("Cannot read"), "{id}: {}", f.details);
+        }
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn robots_blocks_only_the_star_group() {
+        let cases = [
+            ("User-agent: *\nDisallow:\n", Status::Ok, None),
+            ("User-agent: Googlebot\nDisallow: /\n", Status::Ok, None),
+            // Consecutive User-agent lines share one group.
+            (
+                "User-agent: Googlebot\nUser-agent: *\nDisallow: /\n",
+                Status::Warn,
+                Some(3),
+            ),
+            (
+                "User-agent: *\nAllow: /\n\nUser-agent: BadBot\nDisallow: /\n",
+                Status::Ok,
+                None,
+            ),
+            (
+                "user-agent: * # everyone\ndisallow: / # for now\n",
+                Status::Warn,
+                Some(2),
+            ),
+            ("User-agent: *\nDisallow: /admin\n", Status::Ok, None),
+        ];
+        let fe = tree("robots", &[("public/robots.txt", "")]);
+        for (text, status, line) in cases {
+            fs::write(fe.join("public/robots.txt"), text).unwrap();
+            let f = check_robots(&fe);
+            assert_eq!(f.status, status, "{text}");
+            assert_eq!(f.location.as_ref().unwrap().line, line, "{text}");
+        }
+
+        fs::remove_file(fe.join("public/robots.txt")).unwrap();
+        let f = check_robots(&fe);
+        assert_eq!((f.status, f.details.as_str()), (Status::Warn, "Not found"));
+        fs::remove_dir_all(&fe).unwrap();
+    }
+
+    #[test]
+    fn dist_assets_must_exist_and_be_hashed() {
+        let index = "<!doctype html>
+<link rel=\"icon\" href=\"/favicon.ico\">
+<link rel=\"stylesheet\" href=\"/assets/index-Cq3v9Lx0.css\">
+<script type=\"module\" src=\"/assets/index-B8xK2lq9.js?v=1\"></script>
+<script src=\"https://cdn.example.com/lib.js\"></script>
+<script src=\"//cdn.example.com/other.js\"></script>
+";
+        let built = [
+            ("dist/index.html", index),
+            ("dist/favicon.ico", ""),
+            ("dist/assets/index-Cq3v9Lx0.css", ""),
+            ("dist/assets/index-B8xK2lq9.js", ""),
+        ];
+        let fe = tree("dist", &built);
+        let f = check_dist(&fe);
+        assert_eq!(f.status, Status::Ok, "{}", f.details);
+        assert_eq!(f.details, "3 local asset reference(s) resolved");
+
+        fs::remove_file(fe.join("dist/assets/index-B8xK2lq9.js")).unwrap();
+        fs::write(
+            fe.join("dist/index.html"),
+            format!("{index}<script src=\"/assets/main.js\"></script>\n"),
+        )
+        .unwrap();
+        fs::write(fe.join("dist/assets/main.js"), "").unwrap();
+        let f = check_dist(&fe);
+        assert_eq!(f.status, Status::Fail);
+        assert_eq!(
+            f.details,
+            "Asset problems (missing: /assets/index-B8xK2lq9.js?v=1; not content-hashed: /assets/main.js)"
+        );
+        assert_eq!(f.location.as_ref().unwrap().line, Some(4));
+
+        fs::remove_file(fe.join("dist/assets/main.js")).unwrap();
+        fs::remove_dir_all(fe.join("dist")).unwrap();
+        let f = check_dist(&fe);
+        assert_eq!(f.status, Status::Warn);
+        assert!(f.details.ends_with("the frontend has not been built"));
+        fs::remove_dir_all(&fe).unwrap();
+    }
+}
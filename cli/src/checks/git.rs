@@ -0,0 +1,204 @@
+use super::{FindingDoc, listed};
+use crate::report::{Finding, Status, finding, unreadable};
+use crate::util::file::read_optional;
+use crate::util::git::{RepoStatus, head_tags, status};
+use serde_json::Value;
+use std::path::Path;
+
+pub const DOCS: &str = "docs/contributing.md#ci";
+
+pub const FINDINGS: &[FindingDoc] = &[
+    FindingDoc {
+        id: "git.repository",
+        summary: "The root is a git checkout and git is installed",
+        why: "Release state (branch, local changes, tags) can only be verified through git.",
+        inspects: "`git status --porcelain=v2 --branch` run in the repo root.",
+        fix: "Install git and run the CLI from a clone of the repository.",
+        example: None,
+    },
+    FindingDoc {
+        id: "git.branch",
+        summary: "HEAD is on main",
+        why: "Releases are cut from main, which is what CI tests on every push.",
+        inspects: "The current branch reported by `git status`; a detached HEAD counts as not on main.",
+        fix: "Run `git switch main` and pull before building the release.",
+        example: None,
+    },
+    FindingDoc {
+        id: "git.clean",
+        summary: "No uncommitted changes to tracked files",
+        why: "A build from a dirty tree cannot be reproduced from any commit.",
+        inspects: "Staged, unstaged and conflicted tracked paths. Untracked files are only counted in meta.",
+        fix: "Commit or stash the changes (`git stash`), then rebuild.",
+        example: None,
+    },
+    FindingDoc {
+        id: "git.upstream",
+        summary: "The branch is not behind its upstream",
+        why: "Deploying a branch that is behind its remote ships without commits that are already merged.",
+        inspects: "The ahead/behind counts against the configured upstream as of the last fetch; nothing is fetched.",
+        fix: "Run `git pull --ff-only` (after `git fetch`) or set an upstream with `git branch -u origin/main`.",
+        example: None,
+    },
+    FindingDoc {
+        id: "git.tag",
+        summary: "HEAD is tagged with the backend version",
+        why: "A tag matching apps/be/package.json ties the deployed build to a reviewable release.",
+        inspects: "`git tag --points-at HEAD` for `<version>` or `v<version>`, optionally prefixed such as `be-v<version>` or `be@<version>`.",
+        fix: "Tag the release commit: `git tag v<version> && git push origin v<version>`.",
+        example: Some("git tag -a v1.2.0 -m \"Release 1.2.0\"\ngit push origin v1.2.0"),
+    },
+];
+
+const MAIN_BRANCH: &str = "main";
+
+pub fn check(root: &Path) -> Vec<Finding> {
+    let mut out = Vec::new();
+
+    let Some(st) = status(root) else {
+        out.push(
+            finding(
+                "git.repository",
+                "Repository state can be read with git",
+                Status::Warn,
+                "git is not installed or the root is not a git repository",
+            )
+            .fix("Install git and run the CLI from a clone of the repository"),
+        );
+        return out;
+    };
+
+    out.push(finding(
+        "git.repository",
+        "Repository state can be read with git",
+        Status::Ok,
+        "git status succeeded",
+    ));
+    out.push(check_branch(&st));
+    out.push(check_clean(root, &st));
+    out.push(check_upstream(&st));
+    out.push(check_tag(root));
+
+    out
+}
+
+fn check_branch(st: &RepoStatus) -> Finding {
+    let title = "HEAD is on the main branch";
+    match st.branch.as_deref() {
+        Some(MAIN_BRANCH) => finding("git.branch", title, Status::Ok, "On main"),
+        Some(branch) => finding(
+            "git.branch",
+            title,
+            Status::Warn,
+            format!("On branch {branch}"),
+        )
+        .fix("Run: git switch main")
+        .meta("branch", branch),
+        None => finding("git.branch", title, Status::Warn, "HEAD is detached")
+            .fix("Run: git switch main"),
+    }
+}
+
+fn check_clean(root: &Path, st: &RepoStatus) -> Finding {
+    let title = "Working tree has no uncommitted changes";
+    let f = match st.changed.first() {
+        None => finding("git.clean", title, Status::Ok, "No uncommitted changes"),
+        Some(first) => {
+            let details = format!("Uncommitted changes: {}", listed(&st.changed));
+            finding("git.clean", title, Status::Warn, details)
+                .at(root.join(first), None)
+                .fix("Commit or stash the changes, then rebuild")
+                .meta("count", st.changed.len().to_string())
+        }
+    };
+    f.meta("untracked", st.untracked.len().to_string())
+}
+
+fn check_upstream(st: &RepoStatus) -> Finding {
+    let title = "Branch is not behind its upstream";
+    match &st.upstream {
+        None => finding(
+            "git.upstream",
+            title,
+            Status::Warn,
+            "No upstream branch is configured",
+        )
+        .fix("Run: git branch -u origin/main"),
+        Some(upstream) if st.behind > 0 => finding(
+            "git.upstream",
+            title,
+            Status::Warn,
+            format!(
+                "{} commit(s) behind {upstream} (as of the last fetch)",
+                st.behind
+            ),
+        )
+        .fix("Run: git pull --ff-only")
+        .meta("upstream", upstream)
+        .meta("ahead", st.ahead.to_string())
+        .meta("behind", st.behind.to_string()),
+        Some(upstream) => finding(
+            "git.upstream",
+            title,
+            Status::Ok,
+            format!("Up to date with {upstream} (as of the last fetch)"),
+        )
+        .meta("upstream", upstream)
+        .meta("ahead", st.ahead.to_string()),
+    }
+}
+
+fn check_tag(root: &Path) -> Finding {
+    let title = "HEAD is tagged with the backend version";
+    let manifest_path = root.join("apps/be/package.json");
+    let raw = match read_optional(&manifest_path) {
+        Ok(raw) => raw,
+        Err(e) => return unreadable("git.tag", title, &manifest_path, &e),
+    };
+    let version = raw
+        .and_then(|raw| serde_json::from_str::<Value>(&raw).ok())
+        .and_then(|v| v["version"].as_str().map(|s| s.to_string()));
+    let Some(version) = version else {
+        return finding(
+            "git.tag",
+            title,
+            Status::Warn,
+            "apps/be/package.json has no version",
+        )
+        .at(&manifest_path, None)
+        .fix("Set \"version\" in apps/be/package.json");
+    };
+
+    let tags = head_tags(root).unwrap_or_default();
+    match tags.iter().find(|t| tag_matches(t, &version)) {
+        Some(tag) => finding(
+            "git.tag",
+            title,
+            Status::Ok,
+            format!("HEAD is tagged {tag}"),
+        )
+        .meta("version", version),
+        None => finding(
+            "git.tag",
+            title,
+            Status::Warn,
+            if tags.is_empty() {
+                format!("HEAD has no tags (expected v{version})")
+            } else {
+                format!("HEAD tags {} do not match v{version}", tags.join(", "))
+            },
+        )
+        .at(&manifest_path, None)
+        .fix(format!("Run: git tag v{version}"))
+        .meta("version", version),
+    }
+}
+
+// `1.2.0`, `v1.2.0`, `be-v1.2.0`, `be@1.2.0`, `release/v1.2.0`.
+fn tag_matches(tag: &str, version: &str) -> bool {
+    let Some(prefix) = tag.strip_suffix(version) else {
+        return false;
+    };
+    let prefix = prefix.strip_suffix('v').unwrap_or(prefix);
+    prefix.is_empty() || prefix.ends_with(['-', '@', '/'])
+}
@@ -0,0 +1,424 @@
+use super::{FindingDoc, listed};
+use crate::report::{Finding, Status, file_error, finding, invalid, unreadable};
+use crate::util::bunlock::{BunLock, DEP_FIELDS};
+use crate::util::file::read_optional;
+use crate::util::repo::workspace_dirs;
+use crate::util::semver::satisfies;
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const DOCS: &str = "docs/contributing.md#repository-scripts";
+
+pub const FINDINGS: &[FindingDoc] = &[
+    FindingDoc {
+        id: "lock.file",
+        summary: "bun.lock exists and parses",
+        why: "Without a committed lockfile, installs on the server resolve different versions than were tested.",
+        inspects: "Whether `bun.lock` exists at the repo root and is valid JSONC.",
+        fix: "Run `bun install` and commit `bun.lock`; on a parse error resolve merge conflicts first.",
+        example: None,
+    },
+    FindingDoc {
+        id: "lock.workspaces",
+        summary: "bun.lock lists exactly the repo workspaces",
+        why: "A workspace missing from the lock was never installed; a stale one means the lock predates a removal.",
+        inspects: "Workspace directories from the root package.json `workspaces` globs against the lock's `workspaces` keys.",
+        fix: "Run `bun install` and commit the updated `bun.lock`.",
+        example: None,
+    },
+    FindingDoc {
+        id: "lock.dependencies",
+        summary: "Every declared dependency is locked at a satisfying version",
+        why: "`bun install --frozen-lockfile` fails in CI and deploys when a package.json range changed without updating the lock.",
+        inspects: "Each dependency in every workspace package.json: the range recorded in the lock and whether the locked version satisfies it.",
+        fix: "Run `bun install` and commit the updated `bun.lock`.",
+        example: None,
+    },
+    FindingDoc {
+        id: "lock.orphans",
+        summary: "bun.lock has no unreferenced entries",
+        why: "Leftover entries bloat the lock and hide which packages are actually installed.",
+        inspects: "Lock packages not reachable from any workspace dependency.",
+        fix: "Run `bun install` to prune them.",
+        example: None,
+    },
+];
+
+pub fn check(root: &Path) -> Vec<Finding> {
+    let mut out = Vec::new();
+
+    let lock_path = root.join("bun.lock");
+    if !lock_path.exists() {
+        out.push(
+            finding("lock.file", "bun.lock exists", Status::Fail, "Not found")
+                .at(&lock_path, None)
+                .fix("Run: bun install, then commit bun.lock"),
+        );
+        return out;
+    }
+
+    let raw = match fs::read_to_string(&lock_path) {
+        Ok(raw) => raw,
+        Err(e) => {
+            out.push(unreadable("lock.file", "bun.lock exists", &lock_path, &e));
+            return out;
+        }
+    };
+    let lock = match BunLock::parse(&raw) {
+        Ok(lock) => lock,
+        Err(e) => {
+            out.push(
+                finding(
+                    "lock.file",
+                    "bun.lock exists",
+                    Status::Fail,
+                    format!("Failed to parse bun.lock: {e}"),
+                )
+                .at(&lock_path, Some(e.line()))
+                .at_column(e.column())
+                .fix("Resolve merge conflicts or regenerate it with: bun install"),
+            );
+            return out;
+        }
+    };
+
+    out.push(
+        finding("lock.file", "bun.lock exists", Status::Ok, "Found")
+            .at(&lock_path, None)
+            .meta("lockfile_version", lock.lockfile_version.to_string()),
+    );
+
+    // Both workspace findings start from the root package.json, so a broken one stops here.
+    let dirs = match workspace_dirs(root) {
+        Ok(dirs) => dirs,
+        Err(e) => {
+            let manifest_path = root.join("package.json");
+            out.push(file_error(
+                "lock.workspaces",
+                "bun.lock workspaces match the repo workspaces",
+                &manifest_path,
+                &e,
+            ));
+            return out;
+        }
+    };
+    out.push(check_workspaces(&dirs, &lock).at(&lock_path, None));
+    out.push(check_dependencies(root, &dirs, &lock));
+    out.push(check_orphans(&lock).at(&lock_path, None));
+
+    out
+}
+
+fn json_key_line(raw: &str, key: &str) -> Option<usize> {
+    let needle = format!("\"{key}\"");
+    raw.lines().position(|l| l.contains(&needle)).map(|i| i + 1)
+}
+
+fn declared_dependencies(manifest: &Value) -> BTreeMap<String, String> {
+    let mut out = BTreeMap::new();
+    for field in DEP_FIELDS.iter().rev() {
+        if let Some(deps) = manifest[*field].as_object() {
+            for (k, v) in deps {
+                out.insert(k.clone(), v.as_str().unwrap_or("*").to_string());
+            }
+        }
+    }
+    out
+}
+
+fn check_workspaces(dirs: &[String], lock: &BunLock) -> Finding {
+    let dirs: BTreeSet<String> = dirs.iter().cloned().collect();
+    let locked: BTreeSet<String> = lock.workspaces.keys().cloned().collect();
+
+    let missing: Vec<String> = dirs.difference(&locked).cloned().collect();
+    let stale: Vec<String> = locked.difference(&dirs).cloned().collect();
+
+    let title = "bun.lock workspaces match the repo workspaces";
+    if missing.is_empty() && stale.is_empty() {
+        return finding(
+            "lock.workspaces",
+            title,
+            Status::Ok,
+            format!("{} workspace(s) locked", dirs.len()),
+        );
+    }
+
+    let mut problems = Vec::new();
+    if !missing.is_empty() {
+        problems.push(format!("not in bun.lock: {}", missing.join(", ")));
+    }
+    if !stale.is_empty() {
+        problems.push(format!("no longer in repo: {}", stale.join(", ")));
+    }
+    finding(
+        "lock.workspaces",
+        title,
+        Status::Fail,
+        format!("Workspaces out of sync ({})", problems.join("; ")),
+    )
+    .fix("Run: bun install")
+    .meta("count", (missing.len() + stale.len()).to_string())
+}
+
+fn check_dependencies(root: &Path, dirs: &[String], lock: &BunLock) -> Finding {
+    let title = "Every package.json dependency has a satisfying bun.lock entry";
+    let mut problems = Vec::new();
+    // First offending declaration, reported as the finding location.
+    let mut first: Option<(PathBuf, Option<usize>)> = None;
+    let mut checked = 0;
+
+    for dir in dirs {
+        let manifest_path = root.join(dir).join("package.json");
+        // Only the root can lack a package.json; it then declares nothing.
+        let raw = match read_optional(&manifest_path) {
+            Ok(Some(raw)) => raw,
+            Ok(None) => continue,
+            Err(e) => return unreadable("lock.dependencies", title, &manifest_path, &e),
+        };
+        let manifest: Value = match serde_json::from_str(&raw) {
+            Ok(manifest) => manifest,
+            Err(e) => {
+                return invalid(
+                    "lock.dependencies",
+                    title,
+                    &manifest_path,
+                    Some(e.line()),
+                    e,
+                );
+            }
+        };
+        let label = if dir.is_empty() {
+            "(root)"
+        } else {
+            dir.as_str()
+        };
+        let ws_name = manifest["name"].as_str().unwrap_or_default();
+        let locked = lock.workspaces.get(dir).map(|w| w.all_dependencies());
+
+        for (name, range) in declared_dependencies(&manifest) {
+            checked += 1;
+            let scope = if dir.is_empty() { "" } else { ws_name };
+            // One problem per dependency: a stale workspace record hides the resolution check.
+            let problem = match locked.as_ref().map(|l| l.get(&name)) {
+                Some(Some(r)) if r != &range => {
+                    Some(format!("{name} declared as {range} but locked as {r}"))
+                }
+                Some(None) => Some(format!("{name} is not recorded in bun.lock")),
+                _ => match lock.resolve(scope, &name) {
+                    None => Some(format!("{name} has no lock entry")),
+                    Some(key) => match lock.package(&key) {
+                        None => Some(format!("{name} has a malformed lock entry ({key})")),
+                        Some(pkg) => unsatisfied(&name, &range, &pkg.name, &pkg.version),
+                    },
+                },
+            };
+            if let Some(problem) = problem {
+                problems.push(format!("{label}: {problem}"));
+                if first.is_none() {
+                    first = Some((manifest_path.clone(), json_key_line(&raw, &name)));
+                }
+            }
+        }
+    }
+
+    match first {
+        None => finding(
+            "lock.dependencies",
+            title,
+            Status::Ok,
+            format!("{checked} dependency declaration(s) satisfied"),
+        )
+        .meta("checked", checked.to_string()),
+        Some((path, line)) => finding(
+            "lock.dependencies",
+            title,
+            Status::Fail,
+            format!("bun.lock is out of date: {}", problems.join("; ")),
+        )
+        .at(path, line)
+        .fix("Run: bun install and commit the updated bun.lock")
+        .meta("checked", checked.to_string())
+        .meta("count", problems.len().to_string()),
+    }
+}
+
+fn unsatisfied(name: &str, range: &str, locked_name: &str, version: &str) -> Option<String> {
+    if let Some(rest) = range.strip_prefix("workspace:") {
+        return match version.strip_prefix("workspace:") {
+            Some(_) => None,
+            None => Some(format!(
+                "{name} expects workspace:{rest} but is locked to {version}"
+            )),
+        };
+    }
+
+    let range = match range.strip_prefix("npm:") {
+        Some(alias) => match alias.get(1..).and_then(|s| s.find('@')) {
+            Some(at) => &alias[at + 2..],
+            None => "*",
+        },
+        None => range,
+    };
+
+    // Non-semver specifiers (git, file:, link:, tarball URLs, dist tags) only need an entry.
+    match satisfies(version, range) {
+        Some(false) => Some(format!(
+            "{name} requires {range} but bun.lock has {locked_name}@{version}"
+        )),
+        _ => None,
+    }
+}
+
+fn check_orphans(lock: &BunLock) -> Finding {
+    let mut reached: BTreeSet<String> = BTreeSet::new();
+    let mut queue: VecDeque<(String, String)> = VecDeque::new();
+
+    for (dir, ws) in &lock.workspaces {
+        let scope = if dir.is_empty() { "" } else { ws.name.as_str() };
+        for dep in ws.all_dependencies().keys() {
+            queue.push_back((scope.to_string(), dep.clone()));
+        }
+        if !ws.name.is_empty() && lock.packages.contains_key(&ws.name) {
+            reached.insert(ws.name.clone());
+        }
+    }
+
+    while let Some((from, dep)) = queue.pop_front() {
+        let Some(key) = lock.resolve(&from, &dep) else {
+            continue;
+        };
+        if !reached.insert(key.clone()) {
+            continue;
+        }
+        if let Some(pkg) = lock.package(&key) {
+            for d in pkg.dependencies.keys() {
+                queue.push_back((key.clone(), d.clone()));
+            }
+        }
+    }
+
+    let orphans: Vec<String> = lock
+        .packages
+        .keys()
+        .filter(|k| !reached.contains(*k))
+        .cloned()
+        .collect();
+
+    let title = "bun.lock has no entries unreferenced by any package";
+    if orphans.is_empty() {
+        finding(
+            "lock.orphans",
+            title,
+            Status::Ok,
+            format!("All {} lock entries are referenced", lock.packages.len()),
+        )
+    } else {
+        let details = format!("Unreferenced lock entries: {}", listed(&orphans));
+        finding("lock.orphans", title, Status::Warn, details)
+            .fix("Run: bun install to prune entries no package depends on")
+            .meta("count", orphans.len().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tree(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("lock-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        for (path, text) in files {
+            let path = root.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, text).unwrap();
+        }
+        root
+    }
+
+    const LOCK: &str = r#"{
+  "lockfileVersion": 1,
+  "workspaces": {
+    "": { "name": "root" },
+    "apps/fe": { "name": "fe", "dependencies": { "react": "^18.2.0" } },
+  },
+  "packages": {
+    "react": ["react@18.3.1", "", {}, "sha512-x"],
+  },
+}"#;
+
+    #[test]
+    fn malformed_root_manifest_is_reported() {
+        let root = tree(
+            "malformed-root",
+            &[
+                ("bun.lock", LOCK),
+                ("package.json", "{ \"workspaces\": [\"apps/*\"\n"),
+            ],
+        );
+        let found = check(&root);
+        let f = found.iter().find(|f| f.id == "lock.workspaces").unwrap();
+        assert_eq!(f.status, Status::Fail);
+        assert!(f.details.starts_with("Cannot parse"), "{}", f.details);
+        assert!(found.iter().all(|f| f.id != "lock.dependencies"));
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn malformed_workspace_manifest_is_reported() {
+        let root = tree(
+            "malformed-workspace",
+            &[
+                ("bun.lock", LOCK),
+                (
+                    "package.json",
+                    r#"{ "name": "root", "workspaces": ["apps/*"] }"#,
+                ),
+                (
+                    "apps/fe/package.json",
+                    "{ \"name\": \"fe\",\n  \"dependencies\": {\n",
+                ),
+            ],
+        );
+        let found = check(&root);
+        let f = found.iter().find(|f| f.id == "lock.dependencies").unwrap();
+        assert_eq!(f.status, Status::Fail);
+        assert!(f.details.starts_with("Cannot parse"), "{}", f.details);
+        assert!(
+            f.location
+                .as_ref()
+                .unwrap()
+                .path
+                .ends_with("apps/fe/package.json")
+        );
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn drifted_dependency_is_reported_once() {
+        let root = tree(
+            "drifted",
+            &[
+                ("bun.lock", LOCK),
+                (
+                    "package.json",
+                    r#"{ "name": "root", "workspaces": ["apps/*"] }"#,
+                ),
+                (
+                    "apps/fe/package.json",
+                    r#"{ "name": "fe", "dependencies": { "react": "^19.0.0" } }"#,
+                ),
+            ],
+        );
+        let found = check(&root);
+        let f = found.iter().find(|f| f.id == "lock.dependencies").unwrap();
+        assert_eq!(f.status, Status::Fail);
+        assert_eq!(
+            f.details,
+            "bun.lock is out of date: apps/fe: react declared as ^19.0.0 but locked as ^18.2.0"
+        );
+        assert_eq!(f.meta.get("count").map(String::as_str), Some("1"));
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -1,55 +1,488 @@
 pub mod build;
 pub mod ci;
+pub mod cors;
+pub mod custom;
+pub mod db;
+pub mod docker;
+pub mod docs;
 pub mod env;
+pub mod fe;
+pub mod git;
+pub mod lock;
 pub mod nginx;
 pub mod pm2;
 pub mod runtime;
+pub mod rust;
+pub mod security;
+pub mod systemd;
+pub mod tauri;
+pub mod tls;
 
-use crate::args::{CheckArgs, CheckTarget};
+use crate::config::{Config, CustomCheck};
 use crate::error::CliError;
-use crate::report::{Report, Status};
-use std::collections::BTreeMap;
-
-pub fn run_checks(args: &CheckArgs) -> Result<Report, CliError> {
-    let root = args.root.canonicalize().unwrap_or(args.root.clone());
-
-    let targets: Vec<CheckTarget> = match args.target {
-        CheckTarget::All => vec![
-            CheckTarget::Nginx,
-            CheckTarget::Pm2,
-            CheckTarget::Build,
-            CheckTarget::Env,
-            CheckTarget::Ci,
-            CheckTarget::Runtime,
-        ],
-        other => vec![other],
+use crate::i18n::{Lang, localize};
+use crate::profile::Profile;
+use crate::report::{Finding, Report, SCHEMA_VERSION, Status, counts, finding};
+use crate::run;
+use crate::util::glob::glob_match;
+use clap::ValueEnum;
+use std::fmt;
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::time::{Instant, SystemTime};
+
+/// A group of related checks that can be selected on the command line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+pub enum CheckTarget {
+    All,
+    Nginx,
+    Pm2,
+    Build,
+    Env,
+    Ci,
+    Runtime,
+    Lock,
+    Security,
+    Cors,
+    Db,
+    Custom,
+    Git,
+    Fe,
+    Docs,
+    Systemd,
+    Docker,
+    Tls,
+    Tauri,
+    Rust,
+}
+
+/// Inputs shared by every check in a run.
+#[derive(Debug, Clone)]
+pub struct Context {
+    pub root: PathBuf,
+    pub profile: Profile,
+    pub custom: Vec<CustomCheck>,
+}
+
+impl Context {
+    /// The backend env file selected by the profile, e.g. `apps/be/.env.production`.
+    pub fn env_file(&self) -> PathBuf {
+        self.root.join("apps/be").join(&self.profile.env_file)
+    }
+}
+
+/// Long-form documentation for one finding id, printed by `explain`.
+#[derive(Debug)]
+pub struct FindingDoc {
+    pub id: &'static str,
+    /// One line, shown by `check --list`.
+    pub summary: &'static str,
+    pub why: &'static str,
+    pub inspects: &'static str,
+    pub fix: &'static str,
+    pub example: Option<&'static str>,
+}
+
+/// Most items a finding's details list before summarizing the rest as `(+N more)`.
+pub(crate) const MAX_LISTED: usize = 20;
+
+/// Joins `items` with commas, listing at most [`MAX_LISTED`].
+pub(crate) fn listed(items: &[String]) -> String {
+    let mut s = items
+        .iter()
+        .take(MAX_LISTED)
+        .cloned()
+        .collect::<Vec<_>>()
+        .join(", ");
+    if items.len() > MAX_LISTED {
+        s.push_str(&format!(" (+{} more)", items.len() - MAX_LISTED));
+    }
+    s
+}
+
+/// One of the problems a finding aggregates; `text` is what its details list.
+pub(crate) struct Problem {
+    pub path: PathBuf,
+    pub line: Option<usize>,
+    pub text: String,
+}
+
+impl Problem {
+    /// A problem in `path`, shown as `rel` (plus `:line`) in front of `text`.
+    pub fn new(
+        path: impl Into<PathBuf>,
+        rel: &str,
+        line: Option<usize>,
+        text: impl fmt::Display,
+    ) -> Problem {
+        let text = match line {
+            Some(l) => format!("{rel}:{l} {text}"),
+            None => format!("{rel} {text}"),
+        };
+        Problem {
+            path: path.into(),
+            line,
+            text,
+        }
+    }
+}
+
+/// `Ok` with `ok_details` when there are no problems; otherwise `status`, listing every problem
+/// and pointing at the first.
+pub(crate) fn summarize(
+    id: &str,
+    title: &str,
+    status: Status,
+    ok_details: String,
+    problems: Vec<Problem>,
+    fix: &str,
+) -> Finding {
+    let Some(first) = problems.first() else {
+        return finding(id, title, Status::Ok, ok_details);
     };
+    let path = first.path.clone();
+    let line = first.line;
+    let texts: Vec<String> = problems.iter().map(|p| p.text.clone()).collect();
+    finding(id, title, status, listed(&texts))
+        .at(path, line)
+        .fix(fix)
+        .meta("count", problems.len().to_string())
+}
 
-    let mut findings = Vec::new();
-    for t in targets {
-        match t {
-            CheckTarget::Nginx => findings.extend(nginx::check(&root)),
-            CheckTarget::Pm2 => findings.extend(pm2::check(&root)),
-            CheckTarget::Build => findings.extend(build::check(&root)),
-            CheckTarget::Env => findings.extend(env::check(&root)),
-            CheckTarget::Ci => findings.extend(ci::check(&root)),
-            CheckTarget::Runtime => findings.extend(runtime::check()),
-            CheckTarget::All => {}
+/// A registered check target.
+pub struct CheckSpec {
+    pub target: CheckTarget,
+    pub description: &'static str,
+    /// Docs linked from findings that do not name a more specific section.
+    pub docs: &'static str,
+    /// Every finding id the target can emit.
+    pub findings: &'static [FindingDoc],
+    /// Whether `check all` runs it; the others are enabled by profiles or by name.
+    pub default: bool,
+    pub run: fn(&Context) -> Vec<Finding>,
+}
+
+static REGISTRY: [CheckSpec; 19] = [
+    CheckSpec {
+        target: CheckTarget::Nginx,
+        description: "nginx binary and repo config",
+        docs: nginx::DOCS,
+        findings: nginx::FINDINGS,
+        default: true,
+        run: |ctx| nginx::check(&ctx.root),
+    },
+    CheckSpec {
+        target: CheckTarget::Tls,
+        description: "TLS certificates and keys referenced by nginx",
+        docs: tls::DOCS,
+        findings: tls::FINDINGS,
+        default: true,
+        run: |ctx| tls::check(&ctx.root, ctx.profile.cert_warn_days),
+    },
+    CheckSpec {
+        target: CheckTarget::Pm2,
+        description: "pm2 binary and ecosystem config",
+        docs: pm2::DOCS,
+        findings: pm2::FINDINGS,
+        default: true,
+        run: |ctx| pm2::check(&ctx.root),
+    },
+    CheckSpec {
+        target: CheckTarget::Systemd,
+        description: "systemd units for the backend process types",
+        docs: systemd::DOCS,
+        findings: systemd::FINDINGS,
+        default: false,
+        run: |ctx| systemd::check(&ctx.root, &ctx.env_file()),
+    },
+    CheckSpec {
+        target: CheckTarget::Docker,
+        description: "Dockerfiles, .dockerignore and compose.yaml",
+        docs: docker::DOCS,
+        findings: docker::FINDINGS,
+        default: false,
+        run: |ctx| docker::check(&ctx.root, &ctx.env_file()),
+    },
+    CheckSpec {
+        target: CheckTarget::Build,
+        description: "Backend and frontend build outputs",
+        docs: build::DOCS,
+        findings: build::FINDINGS,
+        default: true,
+        run: |ctx| build::check(&ctx.root),
+    },
+    CheckSpec {
+        target: CheckTarget::Fe,
+        description: "Frontend index.html, robots.txt, build assets and output dir",
+        docs: fe::DOCS,
+        findings: fe::FINDINGS,
+        default: true,
+        run: |ctx| fe::check(&ctx.root),
+    },
+    CheckSpec {
+        target: CheckTarget::Env,
+        description: "Backend env file against .env.example",
+        docs: env::DOCS,
+        findings: env::FINDINGS,
+        default: true,
+        run: |ctx| env::check(&ctx.root, &ctx.profile.env_file),
+    },
+    CheckSpec {
+        target: CheckTarget::Ci,
+        description: "CI workflow presence",
+        docs: ci::DOCS,
+        findings: ci::FINDINGS,
+        default: true,
+        run: |ctx| ci::check(&ctx.root),
+    },
+    CheckSpec {
+        target: CheckTarget::Runtime,
+        description: "bun and node on PATH",
+        docs: runtime::DOCS,
+        findings: runtime::FINDINGS,
+        default: true,
+        run: |_| runtime::check(),
+    },
+    CheckSpec {
+        target: CheckTarget::Lock,
+        description: "bun.lock consistency with package.json files",
+        docs: lock::DOCS,
+        findings: lock::FINDINGS,
+        default: true,
+        run: |ctx| lock::check(&ctx.root),
+    },
+    CheckSpec {
+        target: CheckTarget::Docs,
+        description: "English/Korean doc pairs and markdown links",
+        docs: docs::DOCS,
+        findings: docs::FINDINGS,
+        default: true,
+        run: |ctx| docs::check(&ctx.root),
+    },
+    CheckSpec {
+        target: CheckTarget::Tauri,
+        description: "gui-edit Tauri capabilities, CSP and commands",
+        docs: tauri::DOCS,
+        findings: tauri::FINDINGS,
+        default: false,
+        run: |ctx| tauri::check(&ctx.root),
+    },
+    CheckSpec {
+        target: CheckTarget::Rust,
+        description: "Rust crate editions, MSRV, dependencies, lockfiles and CI",
+        docs: rust::DOCS,
+        findings: rust::FINDINGS,
+        default: false,
+        run: |ctx| rust::check(&ctx.root),
+    },
+    CheckSpec {
+        target: CheckTarget::Security,
+        description: "gitignore coverage and committed secrets",
+        docs: security::DOCS,
+        findings: security::FINDINGS,
+        default: false,
+        run: |ctx| security::check(&ctx.root),
+    },
+    CheckSpec {
+        target: CheckTarget::Cors,
+        description: "Production CORS and cookie policy",
+        docs: cors::DOCS,
+        findings: cors::FINDINGS,
+        default: false,
+        run: |ctx| cors::check(&ctx.root, &ctx.env_file()),
+    },
+    CheckSpec {
+        target: CheckTarget::Db,
+        description: "SQLite used as the production database",
+        docs: db::DOCS,
+        findings: db::FINDINGS,
+        default: false,
+        run: |ctx| db::check(&ctx.root, &ctx.env_file()),
+    },
+    CheckSpec {
+        target: CheckTarget::Git,
+        description: "Release state of the git checkout",
+        docs: git::DOCS,
+        findings: git::FINDINGS,
+        default: false,
+        run: |ctx| git::check(&ctx.root),
+    },
+    CheckSpec {
+        target: CheckTarget::Custom,
+        description: "Checks declared as [[checks]] in monorepo-cli.toml",
+        docs: custom::DOCS,
+        findings: custom::FINDINGS,
+        default: true,
+        run: |ctx| custom::check(&ctx.root, &ctx.custom),
+    },
+];
+
+/// Every known check target, in the order `check all` runs them.
+pub fn registry() -> &'static [CheckSpec] {
+    &REGISTRY
+}
+
+/// Looks up the documentation for a finding id, with the target that emits it.
+pub fn finding_doc(id: &str) -> Option<(&'static CheckSpec, &'static FindingDoc)> {
+    registry()
+        .iter()
+        .find_map(|spec| spec.findings.iter().find(|d| d.id == id).map(|d| (spec, d)))
+}
+
+/// What to check and how strictly.
+#[derive(Debug, Clone)]
+pub struct Options {
+    pub root: PathBuf,
+    /// Targets to run; empty or containing [`CheckTarget::All`] runs the default set
+    /// plus the profile's extra checks.
+    pub targets: Vec<CheckTarget>,
+    pub profile: Profile,
+    /// Declarative checks from the config file.
+    pub custom: Vec<CustomCheck>,
+    /// Language of titles, details and remediation; ids and keys stay English.
+    pub lang: Lang,
+    /// Finding ids or globs (`nginx.*`) left out of the report.
+    pub skip: Vec<String>,
+    /// Findings below this status are left out of the report.
+    pub min_status: Status,
+}
+
+impl Options {
+    /// All default checks under the built-in `dev` profile.
+    pub fn new(root: impl Into<PathBuf>) -> Options {
+        Options {
+            root: root.into(),
+            targets: vec![CheckTarget::All],
+            profile: Profile::dev(),
+            custom: Vec::new(),
+            lang: Lang::En,
+            skip: Vec::new(),
+            min_status: Status::Ok,
         }
     }
 
-    let mut counts: BTreeMap<String, usize> = BTreeMap::new();
-    for s in [Status::Ok, Status::Warn, Status::Fail] {
-        counts.insert(format!("{s:?}").to_lowercase(), 0);
+    /// Resolves `profile` and the custom checks from `config` (or `<root>/monorepo-cli.toml` when `None`).
+    pub fn with_profile(
+        root: impl Into<PathBuf>,
+        profile: &str,
+        config: Option<&Path>,
+    ) -> Result<Options, CliError> {
+        let mut opts = Options::new(root);
+        let config = Config::load(&opts.root, config)?;
+        opts.profile = Profile::resolve(profile, &config)?;
+        opts.custom = config.checks;
+        Ok(opts)
     }
-    for f in &findings {
-        let k = format!("{:?}", f.status).to_lowercase();
-        *counts.entry(k).or_insert(0) += 1;
+
+    fn selected(&self, f: &Finding) -> bool {
+        f.status >= self.min_status && !self.skip.iter().any(|p| glob_match(p, &f.id))
     }
 
-    Ok(Report {
-        root: root.to_string_lossy().to_string(),
+    fn resolved_targets(&self) -> Vec<CheckTarget> {
+        let mut out = Vec::new();
+        if self.targets.is_empty() || self.targets.contains(&CheckTarget::All) {
+            out.extend(registry().iter().filter(|s| s.default).map(|s| s.target));
+            out.extend(self.profile.checks.iter().copied());
+        }
+        out.extend(self.targets.iter().filter(|t| **t != CheckTarget::All));
+
+        let mut seen = Vec::new();
+        out.retain(|t| {
+            let fresh = !seen.contains(t);
+            seen.push(*t);
+            fresh
+        });
+        out
+    }
+}
+
+/// Runs the selected checks and applies the profile's severities. Fails when the root is not a
+/// readable directory or a check panics; problems with individual files are findings instead.
+pub fn run_checks(opts: &Options) -> Result<Report, CliError> {
+    let started = SystemTime::now();
+    let timer = Instant::now();
+    let bad_root = |source| CliError::BadRoot {
+        path: opts.root.clone(),
+        source,
+    };
+    let root = opts.root.canonicalize().map_err(bad_root)?;
+    fs::read_dir(&root).map_err(bad_root)?;
+    let ctx = Context {
+        root,
+        profile: opts.profile.clone(),
+        custom: opts.custom.clone(),
+    };
+
+    let mut findings = Vec::new();
+    for t in opts.resolved_targets() {
+        let Some(spec) = registry().iter().find(|s| s.target == t) else {
+            continue;
+        };
+        let found = panic::catch_unwind(AssertUnwindSafe(|| (spec.run)(&ctx))).map_err(|e| {
+            CliError::CheckPanicked {
+                target: spec
+                    .target
+                    .to_possible_value()
+                    .map(|v| v.get_name().to_string())
+                    .unwrap_or_default(),
+                message: e
+                    .downcast_ref::<&str>()
+                    .map(|s| s.to_string())
+                    .or_else(|| e.downcast_ref::<String>().cloned())
+                    .unwrap_or_default(),
+            }
+        })?;
+        // Checks may link a more specific section; otherwise point at the module's docs.
+        findings.extend(found.into_iter().map(|mut f| {
+            debug_assert!(
+                spec.target == CheckTarget::Custom || spec.findings.iter().any(|d| d.id == f.id),
+                "{} has no FindingDoc",
+                f.id
+            );
+            f.docs.get_or_insert_with(|| spec.docs.to_string());
+            f
+        }));
+    }
+
+    for f in &mut findings {
+        ctx.profile.adjust(f);
+    }
+    // After the severities, so `--min-status` sees what the profile made of each finding.
+    findings.retain(|f| opts.selected(f));
+
+    let counts = counts(&findings);
+    let run = run::collect(&ctx.root, started, timer.elapsed());
+    let mut report = Report {
+        schema_version: SCHEMA_VERSION,
+        root: ctx.root.to_string_lossy().to_string(),
+        profile: ctx.profile.name,
+        run,
         counts,
         findings,
-    })
+    };
+    localize(&mut report, opts.lang);
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::markdown::anchors;
+
+    #[test]
+    fn docs_links_point_at_existing_headings() {
+        let repo = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+        for spec in registry() {
+            let (file, anchor) = spec.docs.split_once('#').unwrap_or((spec.docs, ""));
+            let text = fs::read_to_string(repo.join(file))
+                .unwrap_or_else(|e| panic!("{:?}: {file}: {e}", spec.target));
+            assert!(
+                anchor.is_empty() || anchors(&text).iter().any(|a| a == anchor),
+                "{:?} links {}, which has no such heading",
+                spec.target,
+                spec.docs
+            );
+        }
+    }
 }
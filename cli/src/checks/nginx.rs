@@ -1,34 +1,92 @@
-use crate::report::{finding, Finding, Status};
+use super::FindingDoc;
+use crate::report::{Finding, Status, finding};
 use crate::util::command::command_exists;
-use crate::util::repo::has_repo_nginx_config;
+use crate::util::repo::{NGINX_SEARCH_LIMIT, find_nginx_config};
 use std::path::Path;
 
+pub const DOCS: &str = "docs/architecture.md#reverse-proxy-nginx";
+
+pub const FINDINGS: &[FindingDoc] = &[
+    FindingDoc {
+        id: "nginx.command",
+        summary: "nginx is installed on the host",
+        why: "In production nginx terminates HTTP(S) and proxies to the main (8000) and admin (8001) processes. Without it the backend ports would have to be exposed directly.",
+        inspects: "Whether an `nginx` executable is found on PATH.",
+        fix: "Install nginx with your package manager (`apt install nginx`, `brew install nginx`) or add its directory to PATH.",
+        example: None,
+    },
+    FindingDoc {
+        id: "nginx.config_repo",
+        summary: "An nginx config is versioned in the repo",
+        why: "Keeping the reverse proxy config next to the code means port or route changes ship together with the backend that needs them.",
+        inspects: "Files named `*nginx*.conf` or anything under an `nginx/` directory, up to 5000 files from the repo root, in path order. Paths excluded by `.gitignore`/`.ignore` are skipped; if the limit is reached before a config is found the finding warns that the search was incomplete.",
+        fix: "Add `nginx/monorepo.conf` (or similar) that proxies to the backend process types.",
+        example: Some(
+            r#"server {
+    listen 80;
+    server_name app.example.com;
+
+    location /api/ {
+        proxy_pass http://127.0.0.1:8000/;
+    }
+
+    location /admin/ {
+        proxy_pass http://127.0.0.1:8001/;
+    }
+}"#,
+        ),
+    },
+];
+
 pub fn check(root: &Path) -> Vec<Finding> {
     let mut out = Vec::new();
 
     let exists = command_exists("nginx");
-    out.push(finding(
-        "nginx.command",
-        "nginx is available in PATH",
-        if exists { Status::Ok } else { Status::Warn },
-        if exists {
-            "nginx command found"
-        } else {
-            "nginx command not found (install via apt/brew or ensure PATH is set)"
-        },
-    ));
-
-    let has_cfg = has_repo_nginx_config(root);
-    out.push(finding(
-        "nginx.config_repo",
-        "nginx config exists in repo",
-        if has_cfg { Status::Ok } else { Status::Warn },
-        if has_cfg {
-            "Found nginx-related config file(s)"
-        } else {
-            "No nginx config detected (consider adding an nginx/ folder or *.conf)"
-        },
-    ));
+    out.push(if exists {
+        finding(
+            "nginx.command",
+            "nginx is available in PATH",
+            Status::Ok,
+            "nginx command found",
+        )
+    } else {
+        finding(
+            "nginx.command",
+            "nginx is available in PATH",
+            Status::Warn,
+            "nginx command not found",
+        )
+        .fix("Install nginx via apt/brew or ensure PATH is set")
+    });
+
+    let found = find_nginx_config(root);
+    out.push(if !found.files.is_empty() {
+        finding(
+            "nginx.config_repo",
+            "nginx config exists in repo",
+            Status::Ok,
+            "Found nginx-related config file(s)",
+        )
+    } else if found.truncated {
+        finding(
+            "nginx.config_repo",
+            "nginx config exists in repo",
+            Status::Warn,
+            format!(
+                "No nginx config in the first {NGINX_SEARCH_LIMIT} files; the search stopped early"
+            ),
+        )
+        .fix("Gitignore generated or vendored directories so the search reaches the nginx config")
+        .meta("truncated", "true")
+    } else {
+        finding(
+            "nginx.config_repo",
+            "nginx config exists in repo",
+            Status::Warn,
+            "No nginx config detected",
+        )
+        .fix("Add an nginx/ folder or an nginx *.conf that proxies to the backend ports")
+    });
 
     out
 }
@@ -1,34 +1,76 @@
-use crate::report::{finding, Finding, Status};
+use super::FindingDoc;
+use crate::report::{Finding, Status, finding};
 use crate::util::command::command_exists;
 use crate::util::repo::has_pm2_ecosystem;
 use std::path::Path;
 
+pub const DOCS: &str = "docs/architecture.md#running-different-process-types";
+
+pub const FINDINGS: &[FindingDoc] = &[
+    FindingDoc {
+        id: "pm2.command",
+        summary: "pm2 is installed on the host",
+        why: "pm2 keeps the backend processes running, restarts them on crash and starts them on boot.",
+        inspects: "Whether a `pm2` executable is found on PATH.",
+        fix: "Install it globally with `npm i -g pm2` (or `bun add -g pm2`).",
+        example: None,
+    },
+    FindingDoc {
+        id: "pm2.ecosystem",
+        summary: "A pm2 ecosystem file is versioned in the repo",
+        why: "The backend runs as several process types selected by PROCESS_TYPE. An ecosystem file documents and starts all of them with one command.",
+        inspects: "`ecosystem.config.{js,cjs,json,yml,yaml}` at the repo root.",
+        fix: "Add `ecosystem.config.cjs` with one app per PROCESS_TYPE, then run `pm2 start ecosystem.config.cjs`.",
+        example: Some(
+            r#"module.exports = {
+  apps: [
+    { name: "be-main", cwd: "apps/be", script: "dist/index.js", interpreter: "bun", env: { PROCESS_TYPE: "main", PORT: 8000 } },
+    { name: "be-admin", cwd: "apps/be", script: "dist/index.js", interpreter: "bun", env: { PROCESS_TYPE: "admin", ADMIN_PORT: 8001 } },
+    { name: "be-worker", cwd: "apps/be", script: "dist/index.js", interpreter: "bun", env: { PROCESS_TYPE: "worker" } },
+  ],
+};"#,
+        ),
+    },
+];
+
 pub fn check(root: &Path) -> Vec<Finding> {
     let mut out = Vec::new();
 
     let exists = command_exists("pm2");
-    out.push(finding(
-        "pm2.command",
-        "pm2 is available in PATH",
-        if exists { Status::Ok } else { Status::Warn },
-        if exists {
-            "pm2 command found"
-        } else {
-            "pm2 command not found (install with: npm i -g pm2)"
-        },
-    ));
+    out.push(if exists {
+        finding(
+            "pm2.command",
+            "pm2 is available in PATH",
+            Status::Ok,
+            "pm2 command found",
+        )
+    } else {
+        finding(
+            "pm2.command",
+            "pm2 is available in PATH",
+            Status::Warn,
+            "pm2 command not found",
+        )
+        .fix("Install with: npm i -g pm2")
+    });
 
     let has_ecosystem = has_pm2_ecosystem(root);
-    out.push(finding(
-        "pm2.ecosystem",
-        "pm2 ecosystem config exists in repo",
-        if has_ecosystem { Status::Ok } else { Status::Warn },
-        if has_ecosystem {
-            "Found ecosystem.config.*"
-        } else {
-            "No ecosystem.config.* detected"
-        },
-    ));
+    out.push(if has_ecosystem {
+        finding(
+            "pm2.ecosystem",
+            "pm2 ecosystem config exists in repo",
+            Status::Ok,
+            "Found ecosystem.config.*",
+        )
+    } else {
+        finding(
+            "pm2.ecosystem",
+            "pm2 ecosystem config exists in repo",
+            Status::Warn,
+            "No ecosystem.config.* detected",
+        )
+        .fix("Add ecosystem.config.cjs at the repo root with one app per PROCESS_TYPE")
+    });
 
     out
 }
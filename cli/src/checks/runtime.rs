@@ -1,28 +1,66 @@
-use crate::report::{finding, Finding, Status};
+use super::FindingDoc;
+use crate::report::{Finding, Status, finding};
 use crate::util::command::command_exists;
 
+pub const DOCS: &str = "README.md#prerequisites";
+
+pub const FINDINGS: &[FindingDoc] = &[
+    FindingDoc {
+        id: "runtime.bun",
+        summary: "bun is installed",
+        why: "bun is the package manager, script runner and backend runtime of the monorepo.",
+        inspects: "Whether a `bun` executable is found on PATH.",
+        fix: "Install bun with `curl -fsSL https://bun.sh/install | bash` and restart the shell.",
+        example: None,
+    },
+    FindingDoc {
+        id: "runtime.node",
+        summary: "node is installed",
+        why: "pm2 and some npm-based tooling still run on Node.js.",
+        inspects: "Whether a `node` executable is found on PATH.",
+        fix: "Install the current Node.js LTS release.",
+        example: None,
+    },
+];
+
 pub fn check() -> Vec<Finding> {
     let mut out = Vec::new();
 
     let bun = command_exists("bun");
-    out.push(finding(
-        "runtime.bun",
-        "bun is available in PATH",
-        if bun { Status::Ok } else { Status::Fail },
-        if bun { "bun command found" } else { "bun command not found" },
-    ));
+    out.push(if bun {
+        finding(
+            "runtime.bun",
+            "bun is available in PATH",
+            Status::Ok,
+            "bun command found",
+        )
+    } else {
+        finding(
+            "runtime.bun",
+            "bun is available in PATH",
+            Status::Fail,
+            "bun command not found",
+        )
+        .fix("Install bun: curl -fsSL https://bun.sh/install | bash")
+    });
 
     let node = command_exists("node");
-    out.push(finding(
-        "runtime.node",
-        "node is available in PATH (often needed for pm2/npm workflows)",
-        if node { Status::Ok } else { Status::Warn },
-        if node {
-            "node command found"
-        } else {
-            "node command not found"
-        },
-    ));
+    out.push(if node {
+        finding(
+            "runtime.node",
+            "node is available in PATH (often needed for pm2/npm workflows)",
+            Status::Ok,
+            "node command found",
+        )
+    } else {
+        finding(
+            "runtime.node",
+            "node is available in PATH (often needed for pm2/npm workflows)",
+            Status::Warn,
+            "node command not found",
+        )
+        .fix("Install Node.js (LTS) so pm2 and npm-based tooling can run")
+    });
 
     out
 }
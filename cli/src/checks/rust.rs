@@ -0,0 +1,1003 @@
+use super::{FindingDoc, Problem, listed, summarize};
+use crate::report::{Finding, Status, file_error, finding};
+use crate::util::file::{FileError, read_optional, toml_error};
+use crate::util::git::tracked_files;
+use crate::util::path::rel_str;
+use crate::util::walk::walk_files;
+use serde_yaml_ng::Value as Yaml;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const DOCS: &str = "docs/contributing.md#ci";
+
+pub const FINDINGS: &[FindingDoc] = &[
+    FindingDoc {
+        id: "rust.crates",
+        summary: "Rust crates in the repo are discovered",
+        why: "The repo has no Cargo workspace, so each crate resolves its own dependencies and toolchain settings; the other rust.* findings compare them.",
+        inspects: "Every non-ignored Cargo.toml with a `[package]` table, and whether a root Cargo.toml declares a `[workspace]`.",
+        fix: "Nothing to fix; the finding lists the crates the other checks look at.",
+        example: None,
+    },
+    FindingDoc {
+        id: "rust.edition",
+        summary: "All crates use the same Rust edition",
+        why: "Editions change language rules (e.g. 2024's `unsafe extern`, RPIT capture and `gen` keyword), so code and lints moved between crates behave differently.",
+        inspects: "`package.edition` of each crate (2015 when unset).",
+        fix: "Migrate the older crates with `cargo fix --edition`, then set the same `edition` everywhere.",
+        example: Some("[package]\nedition = \"2024\""),
+    },
+    FindingDoc {
+        id: "rust.msrv",
+        summary: "Crates declare a consistent minimum Rust version",
+        why: "Without `rust-version`, a contributor on an older toolchain gets confusing errors instead of a clear \"requires rustc X\" message, and the crates drift apart.",
+        inspects: "`package.rust-version` of each crate: missing, different between crates, or older than the edition requires (2021: 1.56, 2024: 1.85).",
+        fix: "Set `rust-version` in every crate to the oldest toolchain you support.",
+        example: Some("[package]\nedition = \"2024\"\nrust-version = \"1.85\""),
+    },
+    FindingDoc {
+        id: "rust.toolchain",
+        summary: "Pinned toolchains agree with each other and with the MSRV",
+        why: "rust-toolchain.toml overrides the toolchain for everything under its directory; crates pinned to different or too old toolchains build differently locally and in CI.",
+        inspects: "The nearest rust-toolchain.toml or rust-toolchain file of each crate (up to the repo root): channels that differ between crates, or a versioned channel older than the crate's `rust-version`.",
+        fix: "Keep one rust-toolchain.toml at the repo root, or align the channels.",
+        example: Some("[toolchain]\nchannel = \"1.85\"\ncomponents = [\"rustfmt\", \"clippy\"]"),
+    },
+    FindingDoc {
+        id: "rust.dependency_majors",
+        summary: "No dependency is used at different major versions",
+        why: "Two majors of the same crate are compiled twice and their types are incompatible, so sharing code between the crates (e.g. error types built with thiserror) breaks.",
+        inspects: "`dependencies`, `dev-dependencies` and `build-dependencies` of every crate, compared by semver-compatible version (`1.x`, `2.x`, `0.8.x`).",
+        fix: "Upgrade the older requirement so every crate uses the same major version.",
+        example: None,
+    },
+    FindingDoc {
+        id: "rust.lockfile",
+        summary: "Binary crates commit their Cargo.lock",
+        why: "Applications should build with the exact dependency versions that were tested; without a committed lockfile every build may resolve newer releases.",
+        inspects: "Crates with `src/main.rs` or a `[[bin]]` target: `Cargo.lock` must exist next to the manifest and be tracked by git (existence only when git is unavailable).",
+        fix: "Remove `Cargo.lock` from .gitignore and commit the lockfile of each binary crate.",
+        example: None,
+    },
+    FindingDoc {
+        id: "rust.ci",
+        summary: "CI builds and tests every crate",
+        why: "A crate CI does not compile can break on any dependency or toolchain update without anyone noticing until the next release.",
+        inspects: "`run` steps of .github/workflows/*.yml: `cargo test` (and `cargo build`/`check`/`clippy` or `tauri build`) with the crate as `working-directory`, `cd <dir>` or `--manifest-path`.",
+        fix: "Add a step that runs `cargo test` in each crate directory.",
+        example: Some(
+            "- name: Test (gui-edit)\n  working-directory: gui-edit/src-tauri\n  run: cargo test",
+        ),
+    },
+];
+
+const MAX_FILES: usize = 20_000;
+
+// First Rust release supporting each edition.
+const EDITION_MSRV: [(&str, (u64, u64)); 3] =
+    [("2018", (1, 31)), ("2021", (1, 56)), ("2024", (1, 85))];
+
+const TOOLCHAIN_FILES: [&str; 2] = ["rust-toolchain.toml", "rust-toolchain"];
+
+const TOOLCHAIN_TITLE: &str = "Pinned Rust toolchains agree";
+const CI_TITLE: &str = "CI builds and tests every Rust crate";
+
+const DEPENDENCY_TABLES: [&str; 3] = ["dependencies", "dev-dependencies", "build-dependencies"];
+
+struct Crate {
+    name: String,
+    // Directory relative to the root, "" for the root itself.
+    dir: String,
+    manifest: PathBuf,
+    text: String,
+    edition: String,
+    rust_version: Option<String>,
+    binary: bool,
+    dependencies: Vec<Dependency>,
+}
+
+struct Dependency {
+    // The crate pulled in, also for renamed dependencies.
+    package: String,
+    // Semver-compatible version such as `1` or `0.8`.
+    compat: String,
+    // The requirement as written, e.g. `1.0` or `^0.8.2`.
+    req: String,
+}
+
+// Uses of one dependency, by compatible version.
+type Uses<'a> = BTreeMap<&'a str, Vec<(&'a Crate, &'a str)>>;
+
+pub fn check(root: &Path) -> Vec<Finding> {
+    let files = walk_files(root, MAX_FILES);
+    let title = "Rust crates are discovered";
+    let Manifests {
+        crates,
+        workspace,
+        broken,
+    } = manifests(root, &files);
+    // A manifest that cannot be read or parsed fails instead of dropping its crate from the checks.
+    let mut out: Vec<Finding> = broken
+        .iter()
+        .map(|(path, e)| file_error("rust.crates", title, path, e))
+        .collect();
+    if crates.is_empty() {
+        if out.is_empty() {
+            out.push(finding(
+                "rust.crates",
+                title,
+                Status::Ok,
+                "No Cargo.toml with a [package] in the repo",
+            ));
+        }
+        return out;
+    }
+
+    let names: Vec<String> = crates
+        .iter()
+        .map(|c| {
+            format!(
+                "{} in {} (edition {})",
+                c.name,
+                display_dir(&c.dir),
+                c.edition
+            )
+        })
+        .collect();
+    if out.is_empty() {
+        out.push(
+            finding(
+                "rust.crates",
+                title,
+                Status::Ok,
+                format!(
+                    "{}{}",
+                    listed(&names),
+                    if workspace || crates.len() == 1 {
+                        ""
+                    } else {
+                        "; no Cargo workspace"
+                    }
+                ),
+            )
+            .meta("count", crates.len().to_string()),
+        );
+    }
+    out.push(check_edition(&crates));
+    out.push(check_msrv(&crates));
+    // Toolchain and CI findings both compare against the workflows.
+    let workflows = workflows(root);
+    out.push(match &workflows {
+        Ok(w) => check_toolchain(root, &crates, w),
+        Err((path, e)) => file_error("rust.toolchain", TOOLCHAIN_TITLE, path, e),
+    });
+    out.push(check_dependency_majors(&crates));
+    out.push(check_lockfile(root, &crates, &files));
+    out.push(match &workflows {
+        Ok(w) => check_ci(&crates, w),
+        Err((path, e)) => file_error("rust.ci", CI_TITLE, path, e),
+    });
+    out
+}
+
+struct Manifests {
+    crates: Vec<Crate>,
+    // Whether the root Cargo.toml declares a `[workspace]`.
+    workspace: bool,
+    broken: Vec<(PathBuf, FileError)>,
+}
+
+fn manifests(root: &Path, files: &[PathBuf]) -> Manifests {
+    let mut out = Manifests {
+        crates: Vec::new(),
+        workspace: false,
+        broken: Vec::new(),
+    };
+    for manifest in files
+        .iter()
+        .filter(|p| p.file_name().is_some_and(|n| n == "Cargo.toml"))
+    {
+        let text = match fs::read_to_string(manifest) {
+            Ok(text) => text,
+            Err(e) => {
+                out.broken.push((manifest.clone(), FileError::Read(e)));
+                continue;
+            }
+        };
+        let table = match text.parse::<toml::Table>() {
+            Ok(table) => table,
+            Err(e) => {
+                out.broken.push((manifest.clone(), toml_error(&text, &e)));
+                continue;
+            }
+        };
+        if manifest == &root.join("Cargo.toml") {
+            out.workspace = table.contains_key("workspace");
+        }
+        out.crates.extend(parse_crate(root, manifest, text, &table));
+    }
+    out
+}
+
+// `None` for a virtual manifest without `[package]`.
+fn parse_crate(root: &Path, manifest: &Path, text: String, table: &toml::Table) -> Option<Crate> {
+    let package = table.get("package")?.as_table()?;
+    let dir = manifest.parent().unwrap_or(root);
+    let get = |key: &str| {
+        package
+            .get(key)
+            .and_then(|v| v.as_str())
+            .map(str::to_string)
+    };
+    let binary = dir.join("src/main.rs").exists() || table.contains_key("bin");
+    Some(Crate {
+        name: get("name").unwrap_or_default(),
+        dir: rel_str(root, dir),
+        edition: get("edition").unwrap_or_else(|| "2015".to_string()),
+        rust_version: get("rust-version"),
+        binary,
+        dependencies: dependencies(table),
+        manifest: manifest.to_path_buf(),
+        text,
+    })
+}
+
+fn dependencies(table: &toml::Table) -> Vec<Dependency> {
+    let mut out = Vec::new();
+    for section in DEPENDENCY_TABLES {
+        let Some(deps) = table.get(section).and_then(|d| d.as_table()) else {
+            continue;
+        };
+        for (name, spec) in deps {
+            let req = match spec {
+                toml::Value::String(s) => Some(s.as_str()),
+                toml::Value::Table(t) => t.get("version").and_then(|v| v.as_str()),
+                _ => None,
+            };
+            // Path, git and workspace dependencies have no version to compare.
+            let Some(req) = req else {
+                continue;
+            };
+            if let Some(compat) = compatible_version(req) {
+                // A renamed dependency is identified by the crate it pulls in.
+                let package = spec.get("package").and_then(|p| p.as_str()).unwrap_or(name);
+                out.push(Dependency {
+                    package: package.to_string(),
+                    compat,
+                    req: req.to_string(),
+                });
+            }
+        }
+    }
+    out
+}
+
+// The semver-compatible part of a requirement: the major version, or `0.minor` below 1.0.
+fn compatible_version(req: &str) -> Option<String> {
+    let start = req.find(|c: char| c.is_ascii_digit())?;
+    let mut parts = req[start..]
+        .split(|c: char| !c.is_ascii_digit() && c != '.')
+        .next()?
+        .split('.');
+    let major = parts.next()?;
+    if major != "0" {
+        return Some(major.to_string());
+    }
+    Some(match parts.next() {
+        Some(minor) if !minor.is_empty() => format!("0.{minor}"),
+        _ => "0".to_string(),
+    })
+}
+
+fn check_edition(crates: &[Crate]) -> Finding {
+    let title = "Rust crates use the same edition";
+    let newest = crates
+        .iter()
+        .map(|c| c.edition.as_str())
+        .max()
+        .unwrap_or_default();
+    let problems = crates
+        .iter()
+        .filter(|c| c.edition != newest)
+        .map(|c| {
+            problem(
+                c,
+                line_of(&c.text, "edition"),
+                format!("{} uses edition {} (newest is {newest})", c.name, c.edition),
+            )
+        })
+        .collect();
+    summarize(
+        "rust.edition",
+        title,
+        Status::Warn,
+        format!("Every crate uses edition {newest}"),
+        problems,
+        "Migrate with cargo fix --edition and set the same edition in every Cargo.toml",
+    )
+}
+
+fn check_msrv(crates: &[Crate]) -> Finding {
+    let mut problems = Vec::new();
+    // Compared parsed, so `1.85` and `1.85.0` agree.
+    let declared: Vec<(u64, u64)> = crates
+        .iter()
+        .filter_map(|c| c.rust_version.as_deref().and_then(rust_version))
+        .collect();
+    for c in crates {
+        let Some(version) = &c.rust_version else {
+            problems.push(problem(
+                c,
+                None,
+                format!("{} declares no rust-version", c.name),
+            ));
+            continue;
+        };
+        let line = line_of(&c.text, "rust-version");
+        let required = EDITION_MSRV.iter().find(|(e, _)| *e == c.edition);
+        match (rust_version(version), required) {
+            (None, _) => problems.push(problem(
+                c,
+                line,
+                format!("{} has an invalid rust-version `{version}`", c.name),
+            )),
+            (Some(v), Some((edition, min))) if v < *min => problems.push(problem(
+                c,
+                line,
+                format!(
+                    "{} declares rust-version {version} but edition {edition} needs {}.{}",
+                    c.name, min.0, min.1
+                ),
+            )),
+            _ => {}
+        }
+        if let Some(v) = rust_version(version)
+            && declared.iter().any(|d| *d != v)
+        {
+            problems.push(problem(
+                c,
+                line,
+                format!(
+                    "{} declares rust-version {version} unlike the other crates",
+                    c.name
+                ),
+            ));
+        }
+    }
+    summarize(
+        "rust.msrv",
+        "Rust crates declare a consistent minimum Rust version",
+        Status::Warn,
+        format!(
+            "Every crate declares rust-version {}",
+            crates
+                .iter()
+                .find_map(|c| c.rust_version.as_deref())
+                .unwrap_or_default()
+        ),
+        problems,
+        "Set rust-version in every Cargo.toml to the oldest toolchain you support",
+    )
+}
+
+fn check_toolchain(root: &Path, crates: &[Crate], workflows: &Workflows) -> Finding {
+    let title = TOOLCHAIN_TITLE;
+    let mut problems = Vec::new();
+    let mut pinned: Vec<(&Crate, String, PathBuf)> = Vec::new();
+    for c in crates {
+        let (file, channel) = match toolchain_file(root, &root.join(&c.dir)) {
+            Ok(Some(found)) => found,
+            Ok(None) => continue,
+            Err((path, e)) => return file_error("rust.toolchain", title, &path, &e),
+        };
+        if let (Some(pin), Some(msrv)) = (
+            rust_version(&channel),
+            c.rust_version.as_deref().and_then(rust_version),
+        ) && pin < msrv
+        {
+            problems.push(Problem {
+                path: file.clone(),
+                line: None,
+                text: format!(
+                    "{} pins {channel} below {}'s rust-version {}",
+                    rel_str(root, &file),
+                    c.name,
+                    c.rust_version.as_deref().unwrap_or_default()
+                ),
+            });
+        }
+        // rustup applies the file over whatever the workflow installed;
+        // crates sharing a toolchain file get one report for it.
+        let reported = pinned.iter().any(|(_, _, f)| *f == file);
+        for ci in workflows
+            .toolchains
+            .iter()
+            .filter(|t| !reported && t.channel != channel)
+        {
+            problems.push(Problem {
+                path: ci.path.clone(),
+                line: ci.line,
+                text: format!(
+                    "{}:{} installs {} while {} pins {channel}",
+                    rel_str(root, &ci.path),
+                    ci.line.unwrap_or(1),
+                    ci.channel,
+                    rel_str(root, &file)
+                ),
+            });
+        }
+        pinned.push((c, channel, file));
+    }
+    for ci in &workflows.toolchains {
+        let Some(installed) = rust_version(&ci.channel) else {
+            continue;
+        };
+        for c in crates {
+            let Some(msrv) = c.rust_version.as_deref() else {
+                continue;
+            };
+            if rust_version(msrv).is_some_and(|m| installed < m) {
+                problems.push(Problem {
+                    path: ci.path.clone(),
+                    line: ci.line,
+                    text: format!(
+                        "{}:{} installs {} below {}'s rust-version {msrv}",
+                        rel_str(root, &ci.path),
+                        ci.line.unwrap_or(1),
+                        ci.channel,
+                        c.name
+                    ),
+                });
+            }
+        }
+    }
+    if let Some((_, first, _)) = pinned.first() {
+        for (c, channel, file) in pinned.iter().filter(|(_, ch, _)| ch != first) {
+            problems.push(Problem {
+                path: file.clone(),
+                line: None,
+                text: format!(
+                    "{} pins {channel} for {} while other crates use {first}",
+                    rel_str(root, file),
+                    c.name
+                ),
+            });
+        }
+    }
+
+    let ok = if pinned.is_empty() {
+        "No rust-toolchain file; crates build with the installed toolchain".to_string()
+    } else {
+        format!("{} crate(s) pinned to {}", pinned.len(), pinned[0].1)
+    };
+    summarize(
+        "rust.toolchain",
+        title,
+        Status::Warn,
+        ok,
+        problems,
+        "Keep a single rust-toolchain.toml at the repo root, at or above every rust-version",
+    )
+}
+
+// The nearest toolchain file from `dir` up to `root`, with its channel.
+fn toolchain_file(
+    root: &Path,
+    dir: &Path,
+) -> Result<Option<(PathBuf, String)>, (PathBuf, FileError)> {
+    for d in dir.ancestors() {
+        for name in TOOLCHAIN_FILES {
+            let path = d.join(name);
+            let text = match read_optional(&path) {
+                Ok(Some(text)) => text,
+                Ok(None) => continue,
+                Err(e) => return Err((path, FileError::Read(e))),
+            };
+            let channel = match text.parse::<toml::Table>() {
+                Ok(t) => t
+                    .get("toolchain")
+                    .and_then(|t| t.get("channel"))
+                    .and_then(|c| c.as_str())
+                    .unwrap_or("stable")
+                    .to_string(),
+                // The legacy file may hold only the channel name.
+                Err(_) if name == "rust-toolchain" => text.trim().to_string(),
+                Err(e) => return Err((path, toml_error(&text, &e))),
+            };
+            return Ok(Some((path, channel)));
+        }
+        if d == root {
+            break;
+        }
+    }
+    Ok(None)
+}
+
+// `1.85` or `1.85.0` as (major, minor).
+fn rust_version(s: &str) -> Option<(u64, u64)> {
+    let mut parts = s.trim().split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    Some((major, minor))
+}
+
+fn check_dependency_majors(crates: &[Crate]) -> Finding {
+    let mut uses: BTreeMap<&str, Uses> = BTreeMap::new();
+    for c in crates {
+        for d in &c.dependencies {
+            uses.entry(&d.package)
+                .or_default()
+                .entry(&d.compat)
+                .or_default()
+                .push((c, &d.req));
+        }
+    }
+
+    let mut problems = Vec::new();
+    for (name, versions) in uses.iter().filter(|(_, v)| v.len() > 1) {
+        let described: Vec<String> = versions
+            .values()
+            .flatten()
+            .map(|(c, req)| format!("{req} ({})", c.name))
+            .collect();
+        let (c, _) = versions
+            .values()
+            .flatten()
+            .next()
+            .expect("at least one use");
+        let line = c
+            .text
+            .lines()
+            .position(|l| l.split('=').next().is_some_and(|k| k.trim() == *name))
+            .map(|i| i + 1);
+        problems.push(problem(
+            c,
+            line,
+            format!(
+                "{name} has incompatible requirements: {}",
+                described.join(" / ")
+            ),
+        ));
+    }
+
+    summarize(
+        "rust.dependency_majors",
+        "No dependency is used at different major versions",
+        Status::Warn,
+        format!("{} dependency name(s) share one major version", uses.len()),
+        problems,
+        "Upgrade the older requirement so every crate uses the same major version",
+    )
+}
+
+fn check_lockfile(root: &Path, crates: &[Crate], files: &[PathBuf]) -> Finding {
+    let tracked = tracked_files(root);
+    let mut problems = Vec::new();
+    let binaries: Vec<&Crate> = crates.iter().filter(|c| c.binary).collect();
+    for c in &binaries {
+        let lock = root.join(&c.dir).join("Cargo.lock");
+        let rel = rel_str(root, &lock);
+        let text = if !lock.exists() {
+            format!("{rel} is missing")
+        } else if !files.contains(&lock) {
+            format!("{rel} is gitignored")
+        } else if tracked
+            .as_ref()
+            .is_some_and(|t| !t.iter().any(|p| rel_str(Path::new(""), p) == rel))
+        {
+            format!("{rel} exists but is not committed")
+        } else {
+            continue;
+        };
+        problems.push(Problem {
+            path: lock,
+            line: None,
+            text,
+        });
+    }
+
+    summarize(
+        "rust.lockfile",
+        "Binary crates commit their Cargo.lock",
+        Status::Warn,
+        format!(
+            "{} binary crate(s) have a committed Cargo.lock",
+            binaries.len()
+        ),
+        problems,
+        "Remove Cargo.lock from .gitignore and commit the lockfile of each binary crate",
+    )
+}
+
+struct Workflows {
+    // (directory, command) for every cargo or tauri invocation in a `run` step.
+    commands: Vec<(String, String)>,
+    // Toolchains installed by setup actions.
+    toolchains: Vec<CiToolchain>,
+}
+
+struct CiToolchain {
+    path: PathBuf,
+    line: Option<usize>,
+    channel: String,
+}
+
+fn workflows(root: &Path) -> Result<Workflows, (PathBuf, FileError)> {
+    let mut paths: Vec<PathBuf> = fs::read_dir(root.join(".github/workflows"))
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|e| e == "yml" || e == "yaml"))
+        .collect();
+    paths.sort();
+
+    let mut out = Workflows {
+        commands: Vec::new(),
+        toolchains: Vec::new(),
+    };
+    for path in paths {
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) => return Err((path, FileError::Read(e))),
+        };
+        let doc = match serde_yaml_ng::from_str::<Yaml>(&text) {
+            Ok(doc) => doc,
+            Err(e) => {
+                let line = e.location().map(|l| l.line());
+                let message = e.to_string();
+                return Err((path, FileError::Parse { line, message }));
+            }
+        };
+        let Some(jobs) = doc.get("jobs").and_then(Yaml::as_mapping) else {
+            continue;
+        };
+        for job in jobs.values() {
+            let job_dir = job
+                .get("defaults")
+                .and_then(|d| d.get("run"))
+                .and_then(|r| r.get("working-directory"))
+                .and_then(Yaml::as_str)
+                .unwrap_or("");
+            for step in job
+                .get("steps")
+                .and_then(Yaml::as_sequence)
+                .into_iter()
+                .flatten()
+            {
+                if let Some(uses) = step.get("uses").and_then(Yaml::as_str)
+                    && let Some(channel) = setup_toolchain(uses, step)
+                {
+                    out.toolchains.push(CiToolchain {
+                        line: line_of(&text, &format!("uses: {uses}"))
+                            .or_else(|| line_of(&text, &format!("- uses: {uses}"))),
+                        path: path.clone(),
+                        channel,
+                    });
+                }
+                let Some(run) = step.get("run").and_then(Yaml::as_str) else {
+                    continue;
+                };
+                let dir = step
+                    .get("working-directory")
+                    .and_then(Yaml::as_str)
+                    .unwrap_or(job_dir);
+                out.commands.extend(invocations(dir, run));
+            }
+        }
+    }
+    Ok(out)
+}
+
+// The channel a Rust setup action installs: `with.toolchain`, or the ref of
+// `dtolnay/rust-toolchain@<channel>`.
+fn setup_toolchain(uses: &str, step: &Yaml) -> Option<String> {
+    let (action, reference) = uses.split_once('@').unwrap_or((uses, ""));
+    if !matches!(
+        action,
+        "dtolnay/rust-toolchain"
+            | "actions-rs/toolchain"
+            | "actions-rust-lang/setup-rust-toolchain"
+    ) {
+        return None;
+    }
+    if let Some(toolchain) = step
+        .get("with")
+        .and_then(|w| w.get("toolchain"))
+        .and_then(Yaml::as_str)
+    {
+        return Some(toolchain.to_string());
+    }
+    (action == "dtolnay/rust-toolchain" && !matches!(reference, "" | "master" | "v1"))
+        .then(|| reference.to_string())
+}
+
+fn check_ci(crates: &[Crate], workflows: &Workflows) -> Finding {
+    let mut problems = Vec::new();
+    for c in crates {
+        let ran = |verbs: &[&str]| {
+            workflows
+                .commands
+                .iter()
+                .any(|(dir, cmd)| *dir == c.dir && verbs.iter().any(|v| cmd.starts_with(v)))
+        };
+        let tested = ran(&["cargo test", "cargo nextest"]);
+        let built = tested || ran(&["cargo build", "cargo check", "cargo clippy", "tauri build"]);
+        let missing = match (built, tested) {
+            (true, true) => continue,
+            (true, false) => "is not tested in CI",
+            _ => "is neither built nor tested in CI",
+        };
+        problems.push(problem(
+            c,
+            None,
+            format!("{} ({}) {missing}", c.name, display_dir(&c.dir)),
+        ));
+    }
+
+    summarize(
+        "rust.ci",
+        CI_TITLE,
+        Status::Warn,
+        format!("{} crate(s) tested in CI", crates.len()),
+        problems,
+        "Add a step that runs cargo test with the crate directory as working-directory",
+    )
+}
+
+// Cargo and tauri commands in a `run` script, keyed by the crate directory they apply to.
+fn invocations(dir: &str, run: &str) -> Vec<(String, String)> {
+    let mut out = Vec::new();
+    let mut cwd = normalize(dir);
+    for segment in run.lines().flat_map(|l| l.split("&&")) {
+        let words: Vec<&str> = segment.split_whitespace().collect();
+        match words.as_slice() {
+            ["cd", target, ..] => cwd = normalize(&format!("{cwd}/{target}")),
+            ["cargo", rest @ ..] => {
+                let dir = match rest.iter().position(|w| *w == "--manifest-path") {
+                    Some(i) => rest
+                        .get(i + 1)
+                        .map(|p| normalize(&format!("{cwd}/{}", p.trim_end_matches("Cargo.toml"))))
+                        .unwrap_or_else(|| cwd.clone()),
+                    None => cwd.clone(),
+                };
+                // `cargo tauri build` builds the src-tauri crate like `bun tauri build` does.
+                if rest.first() == Some(&"tauri") {
+                    out.push((tauri_dir(&cwd), format!("tauri {}", rest[1..].join(" "))));
+                } else {
+                    out.push((dir, format!("cargo {}", rest.join(" "))));
+                }
+            }
+            _ => {
+                if let Some(i) = words.iter().position(|w| *w == "tauri") {
+                    out.push((
+                        tauri_dir(&cwd),
+                        format!("tauri {}", words[i + 1..].join(" ")),
+                    ));
+                }
+            }
+        }
+    }
+    out
+}
+
+fn tauri_dir(cwd: &str) -> String {
+    if cwd.ends_with("src-tauri") {
+        cwd.to_string()
+    } else {
+        normalize(&format!("{cwd}/src-tauri"))
+    }
+}
+
+// `./a/../b/` -> `b`; "" is the repo root.
+fn normalize(path: &str) -> String {
+    let mut parts: Vec<&str> = Vec::new();
+    for part in path.split('/') {
+        match part {
+            "" | "." => {}
+            ".." => {
+                parts.pop();
+            }
+            p => parts.push(p),
+        }
+    }
+    parts.join("/")
+}
+
+fn display_dir(dir: &str) -> &str {
+    if dir.is_empty() { "." } else { dir }
+}
+
+fn line_of(text: &str, key: &str) -> Option<usize> {
+    text.lines()
+        .position(|l| l.trim_start().starts_with(key))
+        .map(|i| i + 1)
+}
+
+fn problem(c: &Crate, line: Option<usize>, text: String) -> Problem {
+    let rel = if c.dir.is_empty() {
+        "Cargo.toml".to_string()
+    } else {
+        format!("{}/Cargo.toml", c.dir)
+    };
+    Problem::new(&c.manifest, &rel, line, text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tree(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("rust-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        for (path, text) in files {
+            let path = root.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, text).unwrap();
+        }
+        root
+    }
+
+    const CRATE: &str = "[package]\nname = \"a\"\nedition = \"2021\"\nrust-version = \"1.70\"\n";
+
+    #[test]
+    fn compatible_version_keeps_the_semver_compatible_prefix() {
+        assert_eq!(compatible_version("^0.8.2").as_deref(), Some("0.8"));
+        assert_eq!(compatible_version("1.0").as_deref(), Some("1"));
+        assert_eq!(compatible_version("=2.3.4").as_deref(), Some("2"));
+        assert_eq!(compatible_version("~0.0.3").as_deref(), Some("0.0"));
+        assert_eq!(compatible_version("0").as_deref(), Some("0"));
+        assert_eq!(compatible_version(">=1.2, <1.5").as_deref(), Some("1"));
+        assert_eq!(compatible_version("*"), None);
+    }
+
+    #[test]
+    fn invocations_follow_cd_and_manifest_path() {
+        assert_eq!(
+            invocations("", "cd a && cargo test"),
+            [("a".to_string(), "cargo test".to_string())]
+        );
+        assert_eq!(
+            invocations("apps", "cd ../crates/x\ncargo build --release"),
+            [("crates/x".to_string(), "cargo build --release".to_string())]
+        );
+        assert_eq!(
+            invocations("", "cargo test --manifest-path b/Cargo.toml"),
+            [(
+                "b".to_string(),
+                "cargo test --manifest-path b/Cargo.toml".to_string()
+            )]
+        );
+        assert_eq!(
+            invocations("apps/desktop", "cargo tauri build"),
+            [(
+                "apps/desktop/src-tauri".to_string(),
+                "tauri build".to_string()
+            )]
+        );
+        assert_eq!(
+            invocations("apps/desktop/src-tauri", "bun tauri build --debug"),
+            [(
+                "apps/desktop/src-tauri".to_string(),
+                "tauri build --debug".to_string()
+            )]
+        );
+        assert!(invocations("", "bun install && bun run lint").is_empty());
+    }
+
+    #[test]
+    fn normalize_resolves_dots() {
+        assert_eq!(normalize("./a/../b/"), "b");
+        assert_eq!(normalize("a/./b//c"), "a/b/c");
+        assert_eq!(normalize("a/.."), "");
+        assert_eq!(normalize("../a"), "a");
+        assert_eq!(normalize(""), "");
+    }
+
+    #[test]
+    fn broken_manifests_are_reported_instead_of_skipped() {
+        let root = tree(
+            "broken",
+            &[
+                ("a/Cargo.toml", CRATE),
+                ("b/Cargo.toml", "[package]\nname = \"b\"\nedition = \n"),
+            ],
+        );
+        fs::create_dir_all(root.join("c")).unwrap();
+        fs::write(root.join("c/Cargo.toml"), [0xff]).unwrap();
+        let found = check(&root);
+        let crates: Vec<&Finding> = found.iter().filter(|f| f.id == "rust.crates").collect();
+        assert_eq!(crates.len(), 2, "{crates:?}");
+        assert!(crates.iter().all(|f| f.status == Status::Fail));
+        assert!(
+            crates[0].details.starts_with("Cannot parse"),
+            "{}",
+            crates[0].details
+        );
+        assert_eq!(crates[0].location.as_ref().unwrap().line, Some(3));
+        assert!(
+            crates[1].details.starts_with("Cannot read"),
+            "{}",
+            crates[1].details
+        );
+        // The readable crate is still checked.
+        assert!(found.iter().any(|f| f.id == "rust.msrv"));
+
+        let only_broken = tree("only-broken", &[("Cargo.toml", "[package\n")]);
+        let found = check(&only_broken);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].status, Status::Fail);
+        fs::remove_dir_all(&root).unwrap();
+        fs::remove_dir_all(&only_broken).unwrap();
+    }
+
+    #[test]
+    fn broken_workflow_and_toolchain_files_are_reported() {
+        let root = tree(
+            "workflow",
+            &[
+                ("Cargo.toml", CRATE),
+                (".github/workflows/ci.yml", "jobs:\n  test: [\n"),
+            ],
+        );
+        let found = check(&root);
+        for id in ["rust.toolchain", "rust.ci"] {
+            let f = found.iter().find(|f| f.id == id).unwrap();
+            assert_eq!(f.status, Status::Fail, "{id}");
+            assert!(f.details.starts_with("Cannot parse"), "{id}: {}", f.details);
+        }
+
+        fs::remove_file(root.join(".github/workflows/ci.yml")).unwrap();
+        fs::write(root.join("rust-toolchain.toml"), "[toolchain\n").unwrap();
+        let found = check(&root);
+        let f = found.iter().find(|f| f.id == "rust.toolchain").unwrap();
+        assert_eq!(f.status, Status::Fail);
+        assert!(f.details.starts_with("Cannot parse"), "{}", f.details);
+
+        // The legacy file holds just the channel.
+        fs::remove_file(root.join("rust-toolchain.toml")).unwrap();
+        fs::write(root.join("rust-toolchain"), "1.80.0\n").unwrap();
+        assert_eq!(
+            toolchain_file(&root, &root)
+                .unwrap()
+                .map(|(_, c)| c)
+                .as_deref(),
+            Some("1.80.0")
+        );
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn equal_versions_and_shared_toolchain_files_are_reported_once() {
+        let root = tree(
+            "shared",
+            &[
+                (
+                    "a/Cargo.toml",
+                    "[package]\nname = \"a\"\nedition = \"2024\"\nrust-version = \"1.85\"\n",
+                ),
+                (
+                    "b/Cargo.toml",
+                    "[package]\nname = \"b\"\nedition = \"2024\"\nrust-version = \"1.85.0\"\n",
+                ),
+                ("rust-toolchain.toml", "[toolchain]\nchannel = \"1.86.0\"\n"),
+                (
+                    ".github/workflows/ci.yml",
+                    "jobs:\n  test:\n    steps:\n      - uses: dtolnay/rust-toolchain@1.87.0\n",
+                ),
+            ],
+        );
+        let found = check(&root);
+        let msrv = found.iter().find(|f| f.id == "rust.msrv").unwrap();
+        assert_eq!(msrv.status, Status::Ok, "{}", msrv.details);
+        let toolchain = found.iter().find(|f| f.id == "rust.toolchain").unwrap();
+        assert_eq!(
+            toolchain.details.matches("installs 1.87.0 while").count(),
+            1,
+            "{}",
+            toolchain.details
+        );
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
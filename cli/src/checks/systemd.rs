@@ -0,0 +1,368 @@
+use super::{FindingDoc, Problem, listed, summarize};
+use crate::report::{Finding, Status, finding};
+use crate::scaffold::SYSTEMD_DIR;
+use crate::util::env::backend_ports;
+use crate::util::path::rel_str;
+use crate::util::repo::BACKEND_PROCESS_TYPES;
+use crate::util::systemd::{Unit, split_words};
+use crate::util::walk::walk_files;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const DOCS: &str = "docs/architecture.md#running-different-process-types";
+
+const EXAMPLE: &str = "[Service]
+WorkingDirectory=/srv/app/apps/be
+EnvironmentFile=-/srv/app/apps/be/.env.production
+Environment=PROCESS_TYPE=main
+Environment=PORT=8000
+ExecStart=/usr/local/bin/bun dist/index.js
+Restart=on-failure";
+
+pub const FINDINGS: &[FindingDoc] = &[
+    FindingDoc {
+        id: "systemd.units",
+        summary: "systemd units for the backend are versioned in the repo",
+        why: "Hosts that run the backend under systemd instead of pm2 need one unit per process type; keeping them in the repo lets the checks below review them.",
+        inspects: "`*.service` files anywhere in the repo (e.g. deploy/systemd/) that start the backend: they set PROCESS_TYPE, run index.js or use apps/be as working directory.",
+        fix: "Generate them with `monorepo-cli scaffold systemd` and commit deploy/systemd/.",
+        example: None,
+    },
+    FindingDoc {
+        id: "systemd.process_types",
+        summary: "Exactly one unit per backend process type",
+        why: "The backend runs as `main`, `admin` and `worker`; a missing unit leaves that part of the app down and two units for the same type fight over its port.",
+        inspects: "`Environment=PROCESS_TYPE=...` of every backend unit (a unit without it runs as `main`). Unknown values are a failure because the backend refuses to start.",
+        fix: "Keep one unit for each of main, admin and worker.",
+        example: None,
+    },
+    FindingDoc {
+        id: "systemd.environment",
+        summary: "Units set PROCESS_TYPE and the expected port",
+        why: "`main` listens on PORT and `admin` on ADMIN_PORT; nginx proxies to the ports from the env file, so a unit on another port is unreachable.",
+        inspects: "`Environment=` lines: PROCESS_TYPE is set, PORT (main) and ADMIN_PORT (admin) match the profile's env file, `.env.example`, or the 8000/8001 defaults. A port may instead come from an `EnvironmentFile=`.",
+        fix: "Set `Environment=PROCESS_TYPE=<type>` and the matching port variable in each unit.",
+        example: Some(EXAMPLE),
+    },
+    FindingDoc {
+        id: "systemd.working_directory",
+        summary: "Units run from apps/be",
+        why: "ExecStart runs `dist/index.js` relative to the working directory, and the backend resolves prisma and static files from there.",
+        inspects: "`WorkingDirectory=` is set, absolute and ends in `apps/be`.",
+        fix: "Set `WorkingDirectory=/srv/app/apps/be` (your install path).",
+        example: Some(EXAMPLE),
+    },
+    FindingDoc {
+        id: "systemd.restart",
+        summary: "Units restart the backend after a crash",
+        why: "Without a restart policy a single uncaught error takes the process down until someone notices.",
+        inspects: "`Restart=` is `always`, `on-failure` or `on-abnormal`.",
+        fix: "Add `Restart=on-failure` (and `RestartSec=5`) to the [Service] section.",
+        example: Some(EXAMPLE),
+    },
+    FindingDoc {
+        id: "systemd.exec_start",
+        summary: "ExecStart runs the built dist/index.js with bun",
+        why: "Running the TypeScript sources or another runtime skips the production build and the bun-specific APIs the backend uses.",
+        inspects: "`ExecStart=` starts `bun` (directly or through `/usr/bin/env`) with `dist/index.js`.",
+        fix: "Use `ExecStart=/usr/local/bin/bun dist/index.js` (your bun path).",
+        example: Some(EXAMPLE),
+    },
+];
+
+const RESTART_POLICIES: [&str; 3] = ["always", "on-failure", "on-abnormal"];
+
+struct ServiceFile {
+    path: PathBuf,
+    rel: String,
+    unit: Unit,
+}
+
+impl ServiceFile {
+    fn process_type(&self) -> Option<String> {
+        self.unit
+            .environment()
+            .get("PROCESS_TYPE")
+            .map(|(v, _)| v.clone())
+    }
+}
+
+pub fn check(root: &Path, env_file: &Path) -> Vec<Finding> {
+    let mut units: Vec<ServiceFile> = walk_files(root, 20_000)
+        .into_iter()
+        .filter(|p| p.extension().is_some_and(|e| e == "service"))
+        .filter_map(|path| {
+            let text = fs::read_to_string(&path).ok()?;
+            let rel = rel_str(root, &path);
+            Some(ServiceFile {
+                unit: Unit::parse(&text),
+                path,
+                rel,
+            })
+        })
+        .filter(|s| is_backend(&s.unit))
+        .collect();
+    units.sort_by(|a, b| a.rel.cmp(&b.rel));
+
+    let title = "systemd units for the backend exist in repo";
+    if units.is_empty() {
+        return vec![
+            finding(
+                "systemd.units",
+                title,
+                Status::Warn,
+                "No backend *.service units found",
+            )
+            .fix("Generate them with: monorepo-cli scaffold systemd"),
+        ];
+    }
+
+    let names: Vec<String> = units.iter().map(|u| u.rel.clone()).collect();
+    let (port, admin_port) = backend_ports(root, env_file);
+    vec![
+        finding(
+            "systemd.units",
+            title,
+            Status::Ok,
+            format!("Found {} unit(s): {}", units.len(), listed(&names)),
+        ),
+        check_process_types(root, &units),
+        check_environment(&units, port, admin_port),
+        check_working_directory(&units),
+        check_restart(&units),
+        check_exec_start(&units),
+    ]
+}
+
+fn is_backend(unit: &Unit) -> bool {
+    unit.environment().contains_key("PROCESS_TYPE")
+        || unit
+            .get("Service", "ExecStart")
+            .is_some_and(|e| e.value.contains("index.js") || e.value.contains("index.ts"))
+        || unit
+            .get("Service", "WorkingDirectory")
+            .is_some_and(|e| e.value.trim_end_matches('/').ends_with("apps/be"))
+}
+
+fn at(unit: &ServiceFile, line: Option<usize>, text: String) -> Problem {
+    Problem::new(&unit.path, &unit.rel, line, text)
+}
+
+fn check_process_types(root: &Path, units: &[ServiceFile]) -> Finding {
+    let mut by_type: BTreeMap<String, Vec<&ServiceFile>> = BTreeMap::new();
+    for u in units {
+        // The backend defaults to `main` when PROCESS_TYPE is unset.
+        let ty = u.process_type().unwrap_or_else(|| "main".to_string());
+        by_type.entry(ty).or_default().push(u);
+    }
+
+    let mut problems = Vec::new();
+    let mut unknown = false;
+    for (ty, files) in &by_type {
+        let line = files[0]
+            .unit
+            .environment()
+            .get("PROCESS_TYPE")
+            .map(|(_, l)| *l);
+        if !BACKEND_PROCESS_TYPES.contains(&ty.as_str()) {
+            unknown = true;
+            problems.push(at(
+                files[0],
+                line,
+                format!("unknown PROCESS_TYPE={ty} (expected main, admin or worker)"),
+            ));
+        } else if files.len() > 1 {
+            let names: Vec<&str> = files.iter().map(|f| f.rel.as_str()).collect();
+            problems.push(Problem {
+                path: files[0].path.clone(),
+                line,
+                text: format!("PROCESS_TYPE={ty} is started by {}", names.join(", ")),
+            });
+        }
+    }
+    for ty in BACKEND_PROCESS_TYPES {
+        if !by_type.contains_key(ty) {
+            problems.push(Problem {
+                path: root.join(SYSTEMD_DIR),
+                line: None,
+                text: format!("no unit for PROCESS_TYPE={ty}"),
+            });
+        }
+    }
+
+    summarize(
+        "systemd.process_types",
+        "One systemd unit per backend process type",
+        if unknown { Status::Fail } else { Status::Warn },
+        format!("{} unit(s) cover main, admin and worker", units.len()),
+        problems,
+        "Keep exactly one unit for each of main, admin and worker",
+    )
+}
+
+fn check_environment(units: &[ServiceFile], port: u16, admin_port: u16) -> Finding {
+    let mut problems = Vec::new();
+    for u in units {
+        let env = u.unit.environment();
+        let has_env_file = u.unit.get("Service", "EnvironmentFile").is_some();
+        let Some((ty, _)) = env.get("PROCESS_TYPE") else {
+            let line = u.unit.get("Service", "ExecStart").map(|e| e.line);
+            problems.push(at(
+                u,
+                line,
+                "does not set Environment=PROCESS_TYPE (runs as main)".to_string(),
+            ));
+            continue;
+        };
+        let (key, expected) = match ty.as_str() {
+            "main" => ("PORT", port),
+            "admin" => ("ADMIN_PORT", admin_port),
+            _ => continue,
+        };
+        match env.get(key) {
+            Some((value, line)) if value.parse::<u16>().ok() != Some(expected) => {
+                problems.push(at(
+                    u,
+                    Some(*line),
+                    format!("{key}={value}, expected {expected}"),
+                ));
+            }
+            Some(_) => {}
+            None if has_env_file => {}
+            None => {
+                let hint = if ty == "admin" && env.contains_key("PORT") {
+                    " (the admin process reads ADMIN_PORT, not PORT)"
+                } else {
+                    ""
+                };
+                problems.push(at(
+                    u,
+                    env.get("PROCESS_TYPE").map(|(_, l)| *l),
+                    format!("does not set {key}{hint}"),
+                ));
+            }
+        }
+    }
+
+    summarize(
+        "systemd.environment",
+        "systemd units set PROCESS_TYPE and the expected port",
+        Status::Warn,
+        format!("PORT={port}, ADMIN_PORT={admin_port}"),
+        problems,
+        "Set Environment=PROCESS_TYPE=<type> and PORT (main) or ADMIN_PORT (admin) in each unit",
+    )
+}
+
+fn check_working_directory(units: &[ServiceFile]) -> Finding {
+    let mut problems = Vec::new();
+    for u in units {
+        match u.unit.get("Service", "WorkingDirectory") {
+            None => problems.push(at(u, None, "has no WorkingDirectory=".to_string())),
+            Some(e) => {
+                let dir = e.value.trim_start_matches('-').trim_end_matches('/');
+                if !dir.starts_with('/') && !dir.starts_with('~') {
+                    problems.push(at(
+                        u,
+                        Some(e.line),
+                        format!("WorkingDirectory={dir} is not absolute"),
+                    ));
+                } else if !dir.ends_with("apps/be") {
+                    problems.push(at(
+                        u,
+                        Some(e.line),
+                        format!("WorkingDirectory={dir} is not apps/be"),
+                    ));
+                }
+            }
+        }
+    }
+
+    summarize(
+        "systemd.working_directory",
+        "systemd units run from apps/be",
+        Status::Warn,
+        "Every unit runs from apps/be".to_string(),
+        problems,
+        "Set WorkingDirectory= to the absolute path of apps/be on the server",
+    )
+}
+
+fn check_restart(units: &[ServiceFile]) -> Finding {
+    let mut problems = Vec::new();
+    for u in units {
+        match u.unit.get("Service", "Restart") {
+            None => problems.push(at(u, None, "has no Restart= (defaults to no)".to_string())),
+            Some(e) if !RESTART_POLICIES.contains(&e.value.as_str()) => problems.push(at(
+                u,
+                Some(e.line),
+                format!("Restart={} does not restart after a crash", e.value),
+            )),
+            Some(_) => {}
+        }
+    }
+
+    summarize(
+        "systemd.restart",
+        "systemd units restart the backend after a crash",
+        Status::Warn,
+        "Every unit has a restart policy".to_string(),
+        problems,
+        "Add Restart=on-failure to the [Service] section",
+    )
+}
+
+fn check_exec_start(units: &[ServiceFile]) -> Finding {
+    let mut problems = Vec::new();
+    for u in units {
+        let Some(e) = u.unit.get("Service", "ExecStart") else {
+            problems.push(at(u, None, "has no ExecStart=".to_string()));
+            continue;
+        };
+        let mut words = split_words(&e.value).into_iter();
+        // `-`, `@`, `:`, `+` and `!` are systemd prefixes, not part of the path.
+        let mut program = words
+            .next()
+            .unwrap_or_default()
+            .trim_start_matches(['-', '@', ':', '+', '!'])
+            .to_string();
+        if program_name(&program) == "env" {
+            program = words.next().unwrap_or_default();
+        }
+        let args: Vec<String> = words.collect();
+
+        if program_name(&program) != "bun" {
+            problems.push(at(
+                u,
+                Some(e.line),
+                format!("ExecStart runs {program}, not bun"),
+            ));
+        } else if args.iter().any(|a| a.ends_with("src/index.ts")) {
+            problems.push(at(
+                u,
+                Some(e.line),
+                "ExecStart runs src/index.ts instead of the build".to_string(),
+            ));
+        } else if !args.iter().any(|a| a.ends_with("dist/index.js")) {
+            problems.push(at(
+                u,
+                Some(e.line),
+                "ExecStart does not run dist/index.js".to_string(),
+            ));
+        }
+    }
+
+    summarize(
+        "systemd.exec_start",
+        "systemd units run dist/index.js with bun",
+        Status::Warn,
+        "Every unit runs bun dist/index.js".to_string(),
+        problems,
+        "Use ExecStart=/usr/local/bin/bun dist/index.js",
+    )
+}
+
+fn program_name(program: &str) -> &str {
+    program.rsplit('/').next().unwrap_or(program)
+}
@@ -0,0 +1,798 @@
+use super::{FindingDoc, Problem, summarize};
+use crate::report::{Finding, Status, finding, unreadable};
+use crate::util::file::read_optional;
+use crate::util::path::rel_str;
+use crate::util::walk::walk_files;
+use regex::Regex;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+pub const DOCS: &str = "docs/gui-editor-guide.md#tauri-command-pattern-rust---ui";
+
+pub const FINDINGS: &[FindingDoc] = &[
+    FindingDoc {
+        id: "tauri.files",
+        summary: "The Tauri config, capabilities and Cargo.toml parse",
+        why: "Tauri reads these at build time; a syntax error only shows up when someone builds the desktop app.",
+        inspects: "gui-edit/src-tauri/tauri.conf.json, every capabilities/*.json and Cargo.toml. Without a tauri.conf.json the target reports that there is no Tauri app.",
+        fix: "Fix the reported syntax error; `bun tauri info` validates the config as well.",
+        example: None,
+    },
+    FindingDoc {
+        id: "tauri.permissions",
+        summary: "Capabilities do not grant overly broad permissions",
+        why: "The webview can call every permission its capability grants. Process execution, whole permission sets or unscoped filesystem/URL access turn any injected script into full access to the user's machine.",
+        inspects: "`permissions` of each capability: `shell:allow-execute`/`shell:allow-spawn`, `*-all` sets, `fs:*recursive*` permissions, `allow` scopes of `**`, `$HOME/**` or `*` URLs, `windows: [\"*\"]` and `remote` URLs.",
+        fix: "Grant the narrowest permissions the editor uses and scope filesystem access to the project directory.",
+        example: Some(
+            r#"{
+  "identifier": "default",
+  "windows": ["main"],
+  "permissions": [
+    "core:default",
+    { "identifier": "fs:allow-read-text-file", "allow": [{ "path": "$APPDATA/**" }] }
+  ]
+}"#,
+        ),
+    },
+    FindingDoc {
+        id: "tauri.csp",
+        summary: "A Content Security Policy is set and restricts scripts",
+        why: "With `csp: null` Tauri injects no policy, so a script smuggled into the webview (e.g. through rendered file content) runs with access to every command.",
+        inspects: "`app.security.csp` in tauri.conf.json (string or directive map). It must exist, set `default-src` or `script-src`, and the effective script sources must not include `'unsafe-eval'`, `'unsafe-inline'`, `*`, `http:`, `https:` or `data:`.",
+        fix: "Set a restrictive policy and relax single directives only where the UI needs it.",
+        example: Some(
+            r#""security": {
+  "csp": "default-src 'self'; img-src 'self' asset: data:; style-src 'self' 'unsafe-inline'"
+}"#,
+        ),
+    },
+    FindingDoc {
+        id: "tauri.dev_urls",
+        summary: "The production config does not point at a dev server",
+        why: "A localhost URL outside `build.devUrl` makes the built app load a dev server that does not exist on users' machines, showing a blank window.",
+        inspects: "Every string in tauri.conf.json except `build.devUrl` and `build.beforeDevCommand` that is an http(s) URL on localhost, 127.0.0.1, 0.0.0.0 or [::1] (e.g. `build.frontendDist` or a window `url`).",
+        fix: "Point `build.frontendDist` at the built assets (`../dist`) and keep dev URLs in `build.devUrl`.",
+        example: None,
+    },
+    FindingDoc {
+        id: "tauri.metadata",
+        summary: "Identifier and package metadata are not template placeholders",
+        why: "The bundle identifier names the app's data directory and signing identity; changing it after release orphans user data. `authors = [\"you\"]` ships in package metadata.",
+        inspects: "`identifier` in tauri.conf.json (`com.tauri.*`, `*example*` or ending in `.app`) and `authors`/`description` in src-tauri/Cargo.toml (`you`, `A Tauri App`).",
+        fix: "Use a reverse-DNS identifier you own and real package metadata.",
+        example: None,
+    },
+    FindingDoc {
+        id: "tauri.commands",
+        summary: "Every registered command is called from the frontend",
+        why: "Each command in `generate_handler!` is reachable from the webview. One the UI never calls is attack surface without a purpose, often a template leftover like `greet`.",
+        inspects: "Names in `tauri::generate_handler![...]` in src-tauri/src/lib.rs against `invoke(\"name\")` calls in gui-edit/src.",
+        fix: "Remove unused commands from `generate_handler!` (and their functions), or call them from the UI.",
+        example: None,
+    },
+    FindingDoc {
+        id: "tauri.invokes",
+        summary: "Every command the frontend invokes is registered",
+        why: "`invoke` of a command missing from `generate_handler!` rejects at runtime with `command not found`, so the feature silently breaks.",
+        inspects: "`invoke(\"name\")` calls in gui-edit/src against the names in `tauri::generate_handler![...]`.",
+        fix: "Implement the command with `#[tauri::command]` and add it to `generate_handler!`, or remove the call.",
+        example: None,
+    },
+];
+
+const TAURI_DIR: &str = "gui-edit/src-tauri";
+const FRONTEND_DIR: &str = "gui-edit/src";
+
+// Permissions that let the webview run programs or that grant a whole plugin.
+const BROAD_PERMISSIONS: [(&str, &str); 3] = [
+    ("shell:allow-execute", "runs arbitrary programs"),
+    ("shell:allow-spawn", "spawns arbitrary programs"),
+    ("shell:allow-stdin-write", "writes to spawned programs"),
+];
+
+const BROAD_SCOPES: [&str; 6] = ["**", "/**", "*", "$HOME/**", "$HOME/*", "~/**"];
+
+const WEAK_SCRIPT_SOURCES: [&str; 6] = [
+    "'unsafe-eval'",
+    "'unsafe-inline'",
+    "*",
+    "http:",
+    "https:",
+    "data:",
+];
+
+// Keys of tauri.conf.json that are only used by `tauri dev`.
+const DEV_ONLY_KEYS: [&str; 2] = ["build.devUrl", "build.beforeDevCommand"];
+
+struct JsonFile {
+    path: PathBuf,
+    text: String,
+    value: Value,
+}
+
+pub fn check(root: &Path) -> Vec<Finding> {
+    let dir = root.join(TAURI_DIR);
+    let title = "Tauri config files parse";
+    let conf_path = dir.join("tauri.conf.json");
+    if !conf_path.exists() {
+        return vec![finding(
+            "tauri.files",
+            title,
+            Status::Ok,
+            format!("No Tauri app ({TAURI_DIR}/tauri.conf.json not found)"),
+        )];
+    }
+
+    let mut files = Vec::new();
+    let conf = load_json(root, &conf_path, &mut files);
+    let mut capability_paths: Vec<PathBuf> = fs::read_dir(dir.join("capabilities"))
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|e| e == "json"))
+        .collect();
+    capability_paths.sort();
+    let capabilities: Vec<JsonFile> = capability_paths
+        .iter()
+        .filter_map(|p| load_json(root, p, &mut files))
+        .collect();
+    let cargo_path = dir.join("Cargo.toml");
+    let cargo = match fs::read_to_string(&cargo_path) {
+        Ok(text) => match toml::from_str::<toml::Table>(&text) {
+            Ok(table) => Some((text, table)),
+            Err(e) => {
+                files.push(problem(root, &cargo_path, None, e.message().to_string()));
+                None
+            }
+        },
+        Err(e) => {
+            files.push(problem(root, &cargo_path, None, e.to_string()));
+            None
+        }
+    };
+
+    let count = 2 + capabilities.len();
+    let mut out = vec![summarize(
+        "tauri.files",
+        title,
+        Status::Fail,
+        format!("{count} file(s) parsed"),
+        files,
+        "Fix the syntax error in the reported file",
+    )];
+    out.push(check_permissions(root, &capabilities));
+    if let Some(conf) = &conf {
+        out.push(check_csp(conf));
+        out.push(check_dev_urls(root, conf));
+    }
+    out.push(check_metadata(
+        root,
+        conf.as_ref(),
+        &cargo_path,
+        cargo.as_ref(),
+    ));
+    out.extend(check_commands(root));
+    out
+}
+
+fn load_json(root: &Path, path: &Path, problems: &mut Vec<Problem>) -> Option<JsonFile> {
+    let text = match fs::read_to_string(path) {
+        Ok(t) => t,
+        Err(e) => {
+            problems.push(problem(root, path, None, e.to_string()));
+            return None;
+        }
+    };
+    match serde_json::from_str(&text) {
+        Ok(value) => Some(JsonFile {
+            path: path.to_path_buf(),
+            text,
+            value,
+        }),
+        Err(e) => {
+            problems.push(problem(root, path, Some(e.line()), e.to_string()));
+            None
+        }
+    }
+}
+
+fn check_permissions(root: &Path, capabilities: &[JsonFile]) -> Finding {
+    let mut problems = Vec::new();
+    let mut granted = 0;
+    for cap in capabilities {
+        let at = |needle: &str| line_of(&cap.text, needle);
+        if cap.value["windows"]
+            .as_array()
+            .is_some_and(|w| w.iter().any(|w| w == "*"))
+        {
+            problems.push(problem(
+                root,
+                &cap.path,
+                at("\"windows\""),
+                "applies to every window (`windows: [\"*\"]`)".to_string(),
+            ));
+        }
+        if let Some(urls) = cap.value["remote"]["urls"].as_array() {
+            let urls: Vec<&str> = urls.iter().filter_map(Value::as_str).collect();
+            problems.push(problem(
+                root,
+                &cap.path,
+                at("\"remote\""),
+                format!(
+                    "grants its permissions to remote pages ({})",
+                    urls.join(" ")
+                ),
+            ));
+        }
+
+        for p in cap.value["permissions"].as_array().into_iter().flatten() {
+            granted += 1;
+            let (id, scopes) = match p {
+                Value::String(id) => (id.as_str(), Vec::new()),
+                Value::Object(o) => (
+                    o.get("identifier").and_then(Value::as_str).unwrap_or(""),
+                    o.get("allow")
+                        .and_then(Value::as_array)
+                        .into_iter()
+                        .flatten()
+                        .flat_map(|s| ["path", "url"].map(|k| s[k].as_str()))
+                        .flatten()
+                        .collect(),
+                ),
+                _ => continue,
+            };
+            let line = at(&format!("\"{id}\""));
+            if let Some(reason) = broad_reason(id) {
+                problems.push(problem(root, &cap.path, line, format!("{id} {reason}")));
+            }
+            for scope in scopes.iter().filter(|s| BROAD_SCOPES.contains(s)) {
+                problems.push(problem(
+                    root,
+                    &cap.path,
+                    line,
+                    format!("{id} is scoped to `{scope}`"),
+                ));
+            }
+        }
+    }
+
+    summarize(
+        "tauri.permissions",
+        "Tauri capabilities grant narrow permissions",
+        Status::Warn,
+        format!(
+            "{granted} permission(s) in {} capability file(s)",
+            capabilities.len()
+        ),
+        problems,
+        "Grant only the permissions the editor uses and scope filesystem access to the project",
+    )
+}
+
+fn broad_reason(id: &str) -> Option<String> {
+    if let Some((_, reason)) = BROAD_PERMISSIONS.iter().find(|(p, _)| *p == id) {
+        return Some(reason.to_string());
+    }
+    let (plugin, name) = id.split_once(':')?;
+    if name.ends_with("-all") || name == "allow-*" {
+        return Some(format!("grants every {plugin} permission"));
+    }
+    if plugin == "fs" && name.contains("recursive") {
+        return Some("grants recursive filesystem access".to_string());
+    }
+    None
+}
+
+fn check_csp(conf: &JsonFile) -> Finding {
+    let title = "Tauri sets a restrictive Content Security Policy";
+    let line = line_of(&conf.text, "\"csp\"");
+    let fix = "Set app.security.csp, e.g. \"default-src 'self'; img-src 'self' asset: data:\"";
+    let directives = match &conf.value["app"]["security"]["csp"] {
+        Value::String(s) => parse_csp(s),
+        Value::Object(o) => o
+            .iter()
+            .map(|(k, v)| {
+                let sources = match v {
+                    Value::String(s) => s.split_whitespace().map(str::to_string).collect(),
+                    Value::Array(a) => a
+                        .iter()
+                        .filter_map(Value::as_str)
+                        .map(str::to_string)
+                        .collect(),
+                    _ => Vec::new(),
+                };
+                (k.to_lowercase(), sources)
+            })
+            .collect(),
+        _ => {
+            return finding(
+                "tauri.csp",
+                title,
+                Status::Warn,
+                "app.security.csp is null or missing; the webview runs without a Content Security Policy",
+            )
+            .at(&conf.path, line)
+            .fix(fix);
+        }
+    };
+
+    let Some((directive, sources)) = ["script-src", "default-src"]
+        .iter()
+        .find_map(|d| directives.get(*d).map(|s| (*d, s)))
+    else {
+        return finding(
+            "tauri.csp",
+            title,
+            Status::Warn,
+            "The CSP sets neither default-src nor script-src, so scripts are unrestricted",
+        )
+        .at(&conf.path, line)
+        .fix(fix);
+    };
+    let weak: Vec<String> = sources
+        .iter()
+        .filter(|s| WEAK_SCRIPT_SOURCES.contains(&s.as_str()))
+        .cloned()
+        .collect();
+    if weak.is_empty() {
+        finding(
+            "tauri.csp",
+            title,
+            Status::Ok,
+            format!("{directive} {}", sources.join(" ")),
+        )
+        .at(&conf.path, line)
+    } else {
+        finding(
+            "tauri.csp",
+            title,
+            Status::Warn,
+            format!("{directive} allows {}", weak.join(", ")),
+        )
+        .at(&conf.path, line)
+        .fix("Drop the listed sources from the script policy; bundle scripts instead of inlining or evaluating them")
+    }
+}
+
+fn parse_csp(policy: &str) -> BTreeMap<String, Vec<String>> {
+    policy
+        .split(';')
+        .filter_map(|d| {
+            let mut words = d.split_whitespace();
+            let name = words.next()?.to_lowercase();
+            Some((name, words.map(str::to_string).collect()))
+        })
+        .collect()
+}
+
+fn check_dev_urls(root: &Path, conf: &JsonFile) -> Finding {
+    let mut found = Vec::new();
+    dev_urls(&conf.value, "", &mut found);
+    let problems = found
+        .into_iter()
+        .map(|(key, url)| {
+            let line = line_of(&conf.text, &format!("\"{url}\""));
+            problem(
+                root,
+                &conf.path,
+                line,
+                format!("{key} is a dev server URL ({url})"),
+            )
+        })
+        .collect();
+    summarize(
+        "tauri.dev_urls",
+        "Tauri production config has no dev server URLs",
+        Status::Fail,
+        "No localhost URLs outside build.devUrl".to_string(),
+        problems,
+        "Point build.frontendDist at the built assets (../dist) and keep dev URLs in build.devUrl",
+    )
+}
+
+fn dev_urls(value: &Value, key: &str, out: &mut Vec<(String, String)>) {
+    match value {
+        Value::Object(o) => {
+            for (k, v) in o {
+                let key = if key.is_empty() {
+                    k.clone()
+                } else {
+                    format!("{key}.{k}")
+                };
+                if !DEV_ONLY_KEYS.contains(&key.as_str()) {
+                    dev_urls(v, &key, out);
+                }
+            }
+        }
+        Value::Array(a) => {
+            for (i, v) in a.iter().enumerate() {
+                dev_urls(v, &format!("{key}.{i}"), out);
+            }
+        }
+        Value::String(s) if is_local_url(s) => out.push((key.to_string(), s.clone())),
+        _ => {}
+    }
+}
+
+fn is_local_url(s: &str) -> bool {
+    let Some(rest) = s
+        .strip_prefix("http://")
+        .or_else(|| s.strip_prefix("https://"))
+    else {
+        return false;
+    };
+    let host = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    let host = match host.rsplit_once(':') {
+        Some((h, port)) if port.parse::<u16>().is_ok() => h,
+        _ => host,
+    };
+    matches!(host, "localhost" | "127.0.0.1" | "0.0.0.0" | "[::1]")
+}
+
+fn check_metadata(
+    root: &Path,
+    conf: Option<&JsonFile>,
+    cargo_path: &Path,
+    cargo: Option<&(String, toml::Table)>,
+) -> Finding {
+    let mut problems = Vec::new();
+    if let Some(conf) = conf {
+        let id = conf.value["identifier"].as_str().unwrap_or_default();
+        let line = line_of(&conf.text, "\"identifier\"");
+        if id.is_empty() {
+            problems.push(problem(
+                root,
+                &conf.path,
+                line,
+                "has no identifier".to_string(),
+            ));
+        } else if id.starts_with("com.tauri.") || id.contains("example") {
+            problems.push(problem(
+                root,
+                &conf.path,
+                line,
+                format!("identifier `{id}` is a template placeholder"),
+            ));
+        } else if id.ends_with(".app") {
+            problems.push(problem(
+                root,
+                &conf.path,
+                line,
+                format!(
+                    "identifier `{id}` ends in .app and clashes with the macOS bundle extension"
+                ),
+            ));
+        }
+    }
+    if let Some((text, table)) = cargo {
+        let package = table.get("package");
+        let authors: Vec<&str> = package
+            .and_then(|p| p.get("authors"))
+            .and_then(|a| a.as_array())
+            .into_iter()
+            .flatten()
+            .filter_map(|a| a.as_str())
+            .collect();
+        if authors.contains(&"you") {
+            problems.push(problem(
+                root,
+                cargo_path,
+                line_of(text, "authors"),
+                "authors = [\"you\"] is the template default".to_string(),
+            ));
+        }
+        if package
+            .and_then(|p| p.get("description"))
+            .and_then(|d| d.as_str())
+            == Some("A Tauri App")
+        {
+            problems.push(problem(
+                root,
+                cargo_path,
+                line_of(text, "description"),
+                "description \"A Tauri App\" is the template default".to_string(),
+            ));
+        }
+    }
+
+    summarize(
+        "tauri.metadata",
+        "Tauri identifier and package metadata are filled in",
+        Status::Warn,
+        "Identifier and Cargo.toml metadata are set".to_string(),
+        problems,
+        "Use a reverse-DNS identifier you own and fill in authors/description in Cargo.toml",
+    )
+}
+
+fn check_commands(root: &Path) -> Vec<Finding> {
+    let commands_title = "Every registered Tauri command is called from the frontend";
+    let invokes_title = "Every command the frontend invokes is registered";
+    let lib_path = root.join(TAURI_DIR).join("src/lib.rs");
+    let lib = match read_optional(&lib_path) {
+        Ok(Some(lib)) => lib,
+        Ok(None) => return Vec::new(),
+        Err(e) => {
+            return vec![
+                unreadable("tauri.commands", commands_title, &lib_path, &e),
+                unreadable("tauri.invokes", invokes_title, &lib_path, &e),
+            ];
+        }
+    };
+    let registered = registered_commands(&lib);
+    // Both findings compare against every call site, so one unreadable source stops them.
+    let invoked = match invoked_commands(root) {
+        Ok(invoked) => invoked,
+        Err((path, e)) => {
+            return vec![
+                unreadable("tauri.commands", commands_title, &path, &e),
+                unreadable("tauri.invokes", invokes_title, &path, &e),
+            ];
+        }
+    };
+
+    let unused = registered
+        .iter()
+        .filter(|(name, _)| !invoked.contains_key(name))
+        .map(|(name, line)| {
+            problem(
+                root,
+                &lib_path,
+                Some(*line),
+                format!("{name} is never invoked"),
+            )
+        })
+        .collect();
+    let mut missing: Vec<(&PathBuf, usize, &String)> = invoked
+        .iter()
+        .filter(|(name, _)| !registered.iter().any(|(r, _)| r == *name))
+        .map(|(name, (path, line))| (path, *line, name))
+        .collect();
+    missing.sort();
+    let missing = missing
+        .into_iter()
+        .map(|(path, line, name)| {
+            problem(root, path, Some(line), format!("{name} is not registered"))
+        })
+        .collect();
+
+    vec![
+        summarize(
+            "tauri.commands",
+            commands_title,
+            Status::Warn,
+            format!("{} command(s) registered and invoked", registered.len()),
+            unused,
+            "Remove unused commands from generate_handler! or call them from the UI",
+        ),
+        summarize(
+            "tauri.invokes",
+            invokes_title,
+            Status::Fail,
+            format!("{} invoked command(s) registered", invoked.len()),
+            missing,
+            "Add the command to generate_handler! in src-tauri/src/lib.rs or remove the invoke call",
+        ),
+    ]
+}
+
+// Command names in `generate_handler![...]` with their 1-based lines.
+fn registered_commands(lib: &str) -> Vec<(String, usize)> {
+    let Some(start) = lib.find("generate_handler!") else {
+        return Vec::new();
+    };
+    let Some(open) = lib[start..].find('[').map(|i| start + i + 1) else {
+        return Vec::new();
+    };
+    let close = lib[open..].find(']').map_or(lib.len(), |i| open + i);
+    let first_line = lib[..open].matches('\n').count() + 1;
+
+    let mut out = Vec::new();
+    for (i, line) in lib[open..close].lines().enumerate() {
+        let code = line.split("//").next().unwrap_or_default();
+        for item in code.split(',') {
+            let name = item.trim().rsplit("::").next().unwrap_or_default();
+            if !name.is_empty() {
+                out.push((name.to_string(), first_line + i));
+            }
+        }
+    }
+    out
+}
+
+// Commands passed to `invoke` in the frontend, with their first call site.
+fn invoked_commands(
+    root: &Path,
+) -> Result<BTreeMap<String, (PathBuf, usize)>, (PathBuf, io::Error)> {
+    let re = Regex::new(r#"\binvoke\b[^(\n]*\(\s*["'`]([A-Za-z0-9_]+)["'`]"#)
+        .expect("valid invoke pattern");
+    let mut out = BTreeMap::new();
+    for path in walk_files(&root.join(FRONTEND_DIR), 20_000) {
+        let is_source = path.extension().is_some_and(|e| {
+            ["ts", "tsx", "js", "jsx", "vue", "svelte"].contains(&&*e.to_string_lossy())
+        });
+        if !is_source {
+            continue;
+        }
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) => return Err((path, e)),
+        };
+        for (i, line) in text.lines().enumerate() {
+            for c in re.captures_iter(line) {
+                out.entry(c[1].to_string())
+                    .or_insert_with(|| (path.clone(), i + 1));
+            }
+        }
+    }
+    Ok(out)
+}
+
+fn line_of(text: &str, needle: &str) -> Option<usize> {
+    text.lines().position(|l| l.contains(needle)).map(|i| i + 1)
+}
+
+fn problem(root: &Path, path: &Path, line: Option<usize>, text: String) -> Problem {
+    Problem::new(path, &rel_str(root, path), line, text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tree(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("tauri-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        for (path, text) in files {
+            let path = root.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, text).unwrap();
+        }
+        root
+    }
+
+    const LIB: &str =
+        "tauri::Builder::default()\n    .invoke_handler(tauri::generate_handler![open_file])\n";
+
+    #[test]
+    fn unreadable_frontend_source_is_reported() {
+        let root = tree("unreadable", &[("gui-edit/src-tauri/src/lib.rs", LIB)]);
+        // Not UTF-8, so it cannot be read as text.
+        fs::create_dir_all(root.join("gui-edit/src")).unwrap();
+        fs::write(root.join("gui-edit/src/App.tsx"), [0xff, 0xfe]).unwrap();
+        let found = check_commands(&root);
+        assert_eq!(found.len(), 2);
+        for f in &found {
+            assert_eq!(f.status, Status::Fail, "{}", f.id);
+            assert!(f.details.starts_with("Cannot read"), "{}", f.details);
+        }
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn registered_commands_come_from_generate_handler() {
+        let lib = "fn run() {\n    builder.invoke_handler(tauri::generate_handler![\n        commands::open_file, // opens\n        save_file,\n        // greet,\n    ])\n}\n";
+        assert_eq!(
+            registered_commands(lib),
+            [("open_file".to_string(), 3), ("save_file".to_string(), 4)]
+        );
+        assert_eq!(
+            registered_commands("generate_handler![a, b]"),
+            [("a".to_string(), 1), ("b".to_string(), 1)]
+        );
+        assert!(registered_commands("fn main() {}").is_empty());
+    }
+
+    #[test]
+    fn invokes_and_handlers_are_matched_both_ways() {
+        let root = tree(
+            "wiring",
+            &[
+                (
+                    "gui-edit/src-tauri/src/lib.rs",
+                    "generate_handler![open_file, greet]\n",
+                ),
+                (
+                    "gui-edit/src/App.tsx",
+                    "await invoke<string>(\"open_file\", { path });\nconst x = invoked('skip');\n",
+                ),
+                (
+                    "gui-edit/src/save.ts",
+                    "import { invoke } from '@tauri-apps/api/core';\n\n  invoke(`save_file`);\n",
+                ),
+                ("gui-edit/src/notes.md", "invoke(\"ignored\")\n"),
+            ],
+        );
+        let invoked = invoked_commands(&root).unwrap();
+        assert_eq!(
+            invoked.keys().collect::<Vec<_>>(),
+            ["open_file", "save_file"]
+        );
+        assert_eq!(invoked["save_file"].1, 3);
+
+        let found = check_commands(&root);
+        let (commands, invokes) = (&found[0], &found[1]);
+        assert_eq!(commands.status, Status::Warn);
+        assert_eq!(
+            commands.details,
+            "gui-edit/src-tauri/src/lib.rs:1 greet is never invoked"
+        );
+        assert_eq!(invokes.status, Status::Fail);
+        assert_eq!(
+            invokes.details,
+            "gui-edit/src/save.ts:3 save_file is not registered"
+        );
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    fn conf(json: &str) -> JsonFile {
+        JsonFile {
+            path: PathBuf::from("tauri.conf.json"),
+            text: json.to_string(),
+            value: serde_json::from_str(json).unwrap(),
+        }
+    }
+
+    fn csp(value: &str) -> Finding {
+        check_csp(&conf(&format!(
+            r#"{{ "app": {{ "security": {{ "csp": {value} }} }} }}"#
+        )))
+    }
+
+    #[test]
+    fn csp_must_exist_and_restrict_scripts() {
+        assert_eq!(csp("null").status, Status::Warn);
+        assert!(csp("null").details.contains("null or missing"));
+        assert_eq!(check_csp(&conf("{}")).status, Status::Warn);
+
+        let ok = csp(r#""Default-Src 'self'; img-src 'self' data:""#);
+        assert_eq!(
+            (ok.status, ok.details.as_str()),
+            (Status::Ok, "default-src 'self'")
+        );
+
+        let f = csp(r#""img-src *""#);
+        assert_eq!(f.status, Status::Warn);
+        assert!(f.details.contains("neither default-src nor script-src"));
+    }
+
+    #[test]
+    fn script_src_overrides_default_src() {
+        let f = csp(r#""default-src 'self'; script-src 'self' 'unsafe-eval' https:""#);
+        assert_eq!(f.status, Status::Warn);
+        assert_eq!(f.details, "script-src allows 'unsafe-eval', https:");
+
+        // A weak default-src does not matter once script-src is set.
+        let f = csp(r#""default-src *; script-src 'self'""#);
+        assert_eq!(f.status, Status::Ok);
+
+        let f = csp(r#"{ "default-src": "'self'", "script-src": ["'self'", "data:"] }"#);
+        assert_eq!(
+            (f.status, f.details.as_str()),
+            (Status::Warn, "script-src allows data:")
+        );
+    }
+
+    #[test]
+    fn broad_permissions_and_local_urls() {
+        assert!(broad_reason("shell:allow-execute").is_some());
+        assert_eq!(
+            broad_reason("fs:allow-*").as_deref(),
+            Some("grants every fs permission")
+        );
+        assert!(broad_reason("fs:read-all").is_some());
+        assert!(broad_reason("fs:allow-read-text-file").is_none());
+        assert!(broad_reason("core:default").is_none());
+
+        assert!(is_local_url("http://localhost:1420"));
+        assert!(is_local_url("https://127.0.0.1/app"));
+        assert!(is_local_url("http://[::1]:5173?x"));
+        assert!(!is_local_url("https://localhost.example.com"));
+        assert!(!is_local_url("../dist"));
+    }
+}
@@ -0,0 +1,397 @@
+use super::{FindingDoc, Problem, listed, summarize};
+use crate::report::{Finding, Status, finding};
+use crate::util::nginx::{Directive, directives, server_blocks};
+use crate::util::path::rel_str;
+use crate::util::repo::nginx_config_files;
+use crate::util::time::rfc3339;
+use crate::util::tls::{Certificate, name_matches, parse_certificate, private_key_public};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub const DOCS: &str = "docs/architecture.md#reverse-proxy-nginx";
+
+pub const FINDINGS: &[FindingDoc] = &[
+    FindingDoc {
+        id: "tls.files",
+        summary: "Certificates and keys referenced by nginx are readable",
+        why: "nginx refuses to start when an ssl_certificate or ssl_certificate_key file is missing, which takes every site on the host down on reload.",
+        inspects: "`ssl_certificate` and `ssl_certificate_key` in the repo's nginx config. Relative paths are resolved against the config's directory, then the repo root; paths containing variables are skipped.",
+        fix: "Run the checks on the host that has the certificates, or fix the paths in the nginx config.",
+        example: Some(
+            "ssl_certificate     /etc/letsencrypt/live/app.example.com/fullchain.pem;\nssl_certificate_key /etc/letsencrypt/live/app.example.com/privkey.pem;",
+        ),
+    },
+    FindingDoc {
+        id: "tls.expiry",
+        summary: "Certificates are not expired or about to expire",
+        why: "Browsers reject expired certificates outright; renewals that silently stopped working are only noticed when users do.",
+        inspects: "`notAfter` of the first certificate in each ssl_certificate file. Within `cert_warn_days` (30 by default, set per profile in monorepo-cli.toml) it warns; once expired it fails.",
+        fix: "Renew the certificate (e.g. `certbot renew`) and check that the renewal timer is active.",
+        example: Some("[profiles.prod]\ncert_warn_days = 21"),
+    },
+    FindingDoc {
+        id: "tls.key_match",
+        summary: "Each ssl_certificate_key belongs to its certificate",
+        why: "A key from another certificate makes nginx fail with `key values mismatch` on reload.",
+        inspects: "The public key derived from the private key (RSA, EC, or PKCS#8 with an embedded public key) against the certificate's.",
+        fix: "Point ssl_certificate_key at the key the certificate was issued for.",
+        example: None,
+    },
+    FindingDoc {
+        id: "tls.names",
+        summary: "Certificate SANs cover the server_name of their server block",
+        why: "A name missing from the certificate shows a browser warning for that host even though TLS is configured.",
+        inspects: "DNS subject alternative names (or the CN when there are none), including `*.` wildcards, against each `server_name` in the same server block. `_`, regex names and IP addresses are skipped.",
+        fix: "Reissue the certificate with every server_name, or split the hosts into server blocks with their own certificates.",
+        example: None,
+    },
+];
+
+const DAY: i64 = 86_400;
+
+// One `ssl_certificate` with the key and names of its server block.
+struct CertRef {
+    conf: PathBuf,
+    conf_rel: String,
+    line: usize,
+    cert: String,
+    key: Option<Directive>,
+    names: Vec<String>,
+}
+
+pub fn check(root: &Path, warn_days: u32) -> Vec<Finding> {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0);
+    check_at(root, warn_days, now)
+}
+
+// `check` as of `now`, in Unix seconds.
+fn check_at(root: &Path, warn_days: u32, now: i64) -> Vec<Finding> {
+    let refs = cert_refs(root);
+    if refs.is_empty() {
+        return vec![finding(
+            "tls.files",
+            "TLS certificates referenced by nginx are readable",
+            Status::Ok,
+            "No ssl_certificate directives in the repo nginx config",
+        )];
+    }
+
+    let mut files = Vec::new();
+    let mut expiry = Vec::new();
+    let mut expiry_ok = Vec::new();
+    let mut expired = false;
+    let mut keys = Vec::new();
+    let mut mismatch = false;
+    let mut names = Vec::new();
+    let mut loaded = 0;
+
+    for r in &refs {
+        let cert_path = resolve(root, &r.conf, &r.cert);
+        let cert = match fs::read(&cert_path)
+            .map_err(|e| e.to_string())
+            .and_then(|b| parse_certificate(&b))
+        {
+            Ok(c) => c,
+            Err(e) => {
+                files.push(problem(
+                    r,
+                    r.line,
+                    format!("ssl_certificate {}: {e}", cert_path.display()),
+                ));
+                continue;
+            }
+        };
+        loaded += 1;
+
+        let left = cert.not_after - now;
+        let date = date(cert.not_after);
+        let shown = &r.cert;
+        if left < 0 {
+            expired = true;
+            expiry.push(problem(r, r.line, format!("{shown} expired on {date}")));
+        } else if left < i64::from(warn_days) * DAY {
+            expiry.push(problem(
+                r,
+                r.line,
+                format!("{shown} expires on {date} (in {} day(s))", left / DAY),
+            ));
+        } else {
+            let line = format!("{shown} expires on {date}");
+            if !expiry_ok.contains(&line) {
+                expiry_ok.push(line);
+            }
+        }
+
+        match &r.key {
+            Some(key) => check_key(
+                r,
+                key,
+                &cert,
+                &resolve(root, &r.conf, &key.args[0]),
+                &mut files,
+                &mut keys,
+                &mut mismatch,
+            ),
+            None => files.push(problem(
+                r,
+                r.line,
+                "ssl_certificate has no ssl_certificate_key".to_string(),
+            )),
+        }
+
+        let uncovered: Vec<&str> = r
+            .names
+            .iter()
+            .map(String::as_str)
+            .filter(|n| !cert.names.iter().any(|c| name_matches(c, n)))
+            .collect();
+        if !uncovered.is_empty() {
+            names.push(problem(
+                r,
+                r.line,
+                format!(
+                    "{shown} ({}) does not cover {}",
+                    cert.names.join(", "),
+                    uncovered.join(", ")
+                ),
+            ));
+        }
+    }
+
+    let mut out = vec![summarize(
+        "tls.files",
+        "TLS certificates referenced by nginx are readable",
+        Status::Fail,
+        format!("{} certificate(s) loaded", refs.len()),
+        files,
+        "Fix the ssl_certificate/ssl_certificate_key paths or run on the host that has the files",
+    )];
+    if loaded == 0 {
+        return out;
+    }
+    out.push(
+        summarize(
+            "tls.expiry",
+            "TLS certificates are not about to expire",
+            if expired { Status::Fail } else { Status::Warn },
+            listed(&expiry_ok),
+            expiry,
+            "Renew the certificate (e.g. certbot renew) and check the renewal timer",
+        )
+        .meta("warn_days", warn_days.to_string()),
+    );
+    out.push(summarize(
+        "tls.key_match",
+        "TLS private keys match their certificates",
+        if mismatch { Status::Fail } else { Status::Warn },
+        format!("{loaded} certificate(s) checked"),
+        keys,
+        "Point ssl_certificate_key at the key the certificate was issued for",
+    ));
+    out.push(summarize(
+        "tls.names",
+        "TLS certificates cover every server_name",
+        Status::Warn,
+        format!("{loaded} certificate(s) cover their server names"),
+        names,
+        "Reissue the certificate with every server_name of its server block",
+    ));
+    out
+}
+
+fn check_key(
+    r: &CertRef,
+    key: &Directive,
+    cert: &Certificate,
+    key_path: &Path,
+    files: &mut Vec<Problem>,
+    keys: &mut Vec<Problem>,
+    mismatch: &mut bool,
+) {
+    let bytes = match fs::read(key_path) {
+        Ok(b) => b,
+        Err(e) => {
+            files.push(problem(
+                r,
+                key.line,
+                format!("ssl_certificate_key {}: {e}", key_path.display()),
+            ));
+            return;
+        }
+    };
+    match private_key_public(&bytes) {
+        Ok(Some(public)) if public == cert.public_key => {}
+        Ok(Some(_)) => {
+            *mismatch = true;
+            keys.push(problem(
+                r,
+                key.line,
+                format!(
+                    "{} does not belong to the certificate for {}",
+                    key.args[0], cert.subject
+                ),
+            ));
+        }
+        Ok(None) => keys.push(problem(
+            r,
+            key.line,
+            format!(
+                "{}: cannot derive the public key (encrypted or unsupported format)",
+                key.args[0]
+            ),
+        )),
+        Err(e) => {
+            *mismatch = true;
+            keys.push(problem(r, key.line, format!("{}: {e}", key.args[0])));
+        }
+    }
+}
+
+fn cert_refs(root: &Path) -> Vec<CertRef> {
+    let mut out = Vec::new();
+    for conf in nginx_config_files(root) {
+        let Ok(text) = fs::read_to_string(&conf) else {
+            continue;
+        };
+        let conf_rel = rel_str(root, &conf);
+        let blocks = server_blocks(&text);
+        // Directives outside any server block (http level) apply to every server in the file.
+        let block_of = |line: usize| blocks.iter().position(|(s, e)| (*s..=*e).contains(&line));
+        let keys: Vec<Directive> = directives(&text, "ssl_certificate_key")
+            .into_iter()
+            .filter(|k| !k.args.is_empty())
+            .collect();
+        let server_names = directives(&text, "server_name");
+
+        for cert in directives(&text, "ssl_certificate") {
+            let Some(path) = cert.args.first() else {
+                continue;
+            };
+            if path.contains('$') {
+                continue;
+            }
+            let block = block_of(cert.line);
+            let key = keys
+                .iter()
+                .find(|k| block_of(k.line) == block)
+                .or_else(|| keys.iter().find(|k| block_of(k.line).is_none()))
+                .cloned();
+            let names = server_names
+                .iter()
+                .filter(|d| block.is_none() || block_of(d.line) == block)
+                .flat_map(|d| d.args.iter())
+                .filter_map(|n| host_name(n))
+                .collect();
+            out.push(CertRef {
+                conf: conf.clone(),
+                conf_rel: conf_rel.clone(),
+                line: cert.line,
+                cert: path.clone(),
+                key,
+                names,
+            });
+        }
+    }
+    out
+}
+
+// The host a server_name must be covered for; `None` for catch-alls, regexes and IPs.
+fn host_name(name: &str) -> Option<String> {
+    if name == "_" || name.is_empty() || name.starts_with('~') {
+        return None;
+    }
+    if name.parse::<std::net::IpAddr>().is_ok() {
+        return None;
+    }
+    // `.example.com` is shorthand for `example.com` and `*.example.com`.
+    Some(name.strip_prefix('.').unwrap_or(name).to_lowercase())
+}
+
+fn resolve(root: &Path, conf: &Path, value: &str) -> PathBuf {
+    let p = Path::new(value);
+    if p.is_absolute() {
+        return p.to_path_buf();
+    }
+    let beside = conf.parent().unwrap_or(root).join(p);
+    if beside.exists() {
+        beside
+    } else {
+        root.join(p)
+    }
+}
+
+fn date(unix: i64) -> String {
+    let t = UNIX_EPOCH + Duration::from_secs(unix.max(0) as u64);
+    rfc3339(t)[..10].to_string()
+}
+
+fn problem(r: &CertRef, line: usize, text: String) -> Problem {
+    Problem::new(&r.conf, &r.conf_rel, Some(line), text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::tls::tests::{OTHER_RSA, RSA_CERT, RSA_PKCS1};
+
+    fn tree(name: &str, key: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("tls-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("nginx/certs")).unwrap();
+        fs::write(
+            root.join("nginx/site.conf"),
+            "server {\n  listen 443 ssl;\n  server_name example.com www.example.com;\n  ssl_certificate certs/site.crt;\n  ssl_certificate_key certs/site.key;\n}\n",
+        )
+        .unwrap();
+        fs::write(root.join("nginx/certs/site.crt"), RSA_CERT).unwrap();
+        fs::write(root.join("nginx/certs/site.key"), key).unwrap();
+        root
+    }
+
+    fn by_id<'a>(found: &'a [Finding], id: &str) -> &'a Finding {
+        found.iter().find(|f| f.id == id).unwrap()
+    }
+
+    #[test]
+    fn expiry_thresholds() {
+        let root = tree("expiry", RSA_PKCS1);
+        let not_after = parse_certificate(RSA_CERT.as_bytes()).unwrap().not_after;
+        let status = |days_left: i64| {
+            let found = check_at(&root, 30, not_after - days_left * DAY);
+            by_id(&found, "tls.expiry").status
+        };
+        assert_eq!(status(31), Status::Ok);
+        assert_eq!(status(30), Status::Ok);
+        assert_eq!(status(29), Status::Warn);
+        assert_eq!(status(0), Status::Warn);
+        assert_eq!(status(-1), Status::Fail);
+
+        let found = check_at(&root, 30, not_after - 10 * DAY);
+        let f = by_id(&found, "tls.expiry");
+        assert!(f.details.ends_with("(in 10 day(s))"), "{}", f.details);
+        // Every other finding is unaffected by the date.
+        assert_eq!(by_id(&found, "tls.files").status, Status::Ok);
+        assert_eq!(by_id(&found, "tls.key_match").status, Status::Ok);
+        assert_eq!(by_id(&found, "tls.names").status, Status::Ok);
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn key_for_another_certificate_fails() {
+        let root = tree("mismatch", OTHER_RSA);
+        let found = check(&root, 30);
+        let f = by_id(&found, "tls.key_match");
+        assert_eq!(f.status, Status::Fail);
+        assert!(
+            f.details
+                .contains("certs/site.key does not belong to the certificate for CN=example.com"),
+            "{}",
+            f.details
+        );
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,178 @@
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use crate::checks::registry;
+use crate::error::CliError;
+use crate::report::Status;
+
+pub const CONFIG_FILE: &str = "monorepo-cli.toml";
+
+/// Contents of `monorepo-cli.toml`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    #[serde(default)]
+    pub profiles: BTreeMap<String, ProfileConfig>,
+    /// Project-specific checks declared as `[[checks]]`, run by the `custom` target.
+    #[serde(default)]
+    pub checks: Vec<CustomCheck>,
+}
+
+/// One `[profiles.<name>]` table; unset fields keep the values of the profile it extends.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProfileConfig {
+    pub extends: Option<String>,
+    pub env_file: Option<String>,
+    /// Days before expiry at which `tls.expiry` starts warning.
+    pub cert_warn_days: Option<u32>,
+    #[serde(default)]
+    pub checks: Vec<String>,
+    #[serde(default)]
+    pub severity: BTreeMap<String, Status>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum CheckKind {
+    FileExists,
+    DirExists,
+    FileMatchesRegex,
+    CommandExists,
+    CommandOutputMatches,
+    JsonPathEquals,
+    YamlPathEquals,
+    TomlPathEquals,
+}
+
+impl CheckKind {
+    /// Name as written in the config file.
+    pub fn name(self) -> &'static str {
+        match self {
+            CheckKind::FileExists => "file-exists",
+            CheckKind::DirExists => "dir-exists",
+            CheckKind::FileMatchesRegex => "file-matches-regex",
+            CheckKind::CommandExists => "command-exists",
+            CheckKind::CommandOutputMatches => "command-output-matches",
+            CheckKind::JsonPathEquals => "json-path-equals",
+            CheckKind::YamlPathEquals => "yaml-path-equals",
+            CheckKind::TomlPathEquals => "toml-path-equals",
+        }
+    }
+}
+
+/// One declarative check. Which of the optional fields are required depends on `kind`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CustomCheck {
+    pub id: String,
+    pub title: String,
+    pub kind: CheckKind,
+    /// Status reported when the check is not satisfied.
+    #[serde(default = "default_severity")]
+    pub severity: Status,
+    pub fix: Option<String>,
+    /// File or directory, relative to the repo root.
+    pub path: Option<String>,
+    pub pattern: Option<String>,
+    pub command: Option<String>,
+    #[serde(default)]
+    pub args: Vec<String>,
+    /// Dotted path into a json/yaml/toml document, e.g. `engines.bun` or `apps.0.name`.
+    pub key: Option<String>,
+    pub value: Option<serde_json::Value>,
+}
+
+fn default_severity() -> Status {
+    Status::Warn
+}
+
+impl CustomCheck {
+    // Checked when the config is loaded and again before the rule runs, since library callers
+    // can build rules directly.
+    pub(crate) fn validate(&self) -> Result<(), String> {
+        if self.id.is_empty() || self.id.contains(char::is_whitespace) {
+            return Err(format!("invalid id `{}`", self.id));
+        }
+        if self.severity == Status::Ok {
+            return Err("severity must be `warn` or `fail`".to_string());
+        }
+
+        let needs: &[(&str, bool)] = match self.kind {
+            CheckKind::FileExists | CheckKind::DirExists => &[("path", self.path.is_some())],
+            CheckKind::FileMatchesRegex => &[
+                ("path", self.path.is_some()),
+                ("pattern", self.pattern.is_some()),
+            ],
+            CheckKind::CommandExists => &[("command", self.command.is_some())],
+            CheckKind::CommandOutputMatches => &[
+                ("command", self.command.is_some()),
+                ("pattern", self.pattern.is_some()),
+            ],
+            CheckKind::JsonPathEquals | CheckKind::YamlPathEquals | CheckKind::TomlPathEquals => &[
+                ("path", self.path.is_some()),
+                ("key", self.key.is_some()),
+                ("value", self.value.is_some()),
+            ],
+        };
+        if let Some((field, _)) = needs.iter().find(|(_, present)| !present) {
+            return Err(format!("missing `{field}` for kind {}", self.kind.name()));
+        }
+        if let Some(pattern) = &self.pattern {
+            regex::Regex::new(pattern).map_err(|e| format!("invalid pattern: {e}"))?;
+        }
+        Ok(())
+    }
+}
+
+impl Config {
+    /// Loads `path`, or `<root>/monorepo-cli.toml` when it exists. A missing default file is not an error.
+    pub fn load(root: &Path, path: Option<&Path>) -> Result<Config, CliError> {
+        let (path, required) = match path {
+            Some(p) => (p.to_path_buf(), true),
+            None => (root.join(CONFIG_FILE), false),
+        };
+        if !required && !path.exists() {
+            return Ok(Config::default());
+        }
+
+        let raw = fs::read_to_string(&path).map_err(|source| CliError::Unreadable {
+            path: path.clone(),
+            source,
+        })?;
+        let config: Config = toml::from_str(&raw).map_err(|e| parse_error(&path, &raw, e))?;
+
+        let builtin: Vec<&str> = registry()
+            .iter()
+            .flat_map(|s| s.findings.iter().map(|d| d.id))
+            .collect();
+        for (i, check) in config.checks.iter().enumerate() {
+            let duplicate = builtin.contains(&check.id.as_str())
+                || config.checks[..i].iter().any(|c| c.id == check.id);
+            let result = if duplicate {
+                Err(format!("duplicate id `{}`", check.id))
+            } else {
+                check.validate()
+            };
+            result
+                .map_err(|e| CliError::Config(format!("{}: checks[{i}]: {e}", path.display())))?;
+        }
+        Ok(config)
+    }
+}
+
+fn parse_error(path: &Path, raw: &str, e: toml::de::Error) -> CliError {
+    let message = match e.span() {
+        Some(span) => {
+            let line = raw[..span.start].matches('\n').count() + 1;
+            format!("line {line}: {}", e.message())
+        }
+        None => e.message().to_string(),
+    };
+    CliError::ConfigParse {
+        path: path.to_path_buf(),
+        message,
+    }
+}
@@ -1,7 +1,72 @@
+use std::io;
+use std::path::PathBuf;
 use thiserror::Error;
 
+/// Exit codes of the `cli` binary. 0–2 describe the checks; the others mean the tool could not
+/// do its job, so CI can tell a failing deployment from a broken invocation.
+pub mod exit {
+    /// Every reported finding is ok.
+    pub const OK: i32 = 0;
+    /// At least one warning and no failures.
+    pub const WARN: i32 = 1;
+    /// At least one failure, or a warning under `--strict`.
+    pub const FAIL: i32 = 2;
+    /// Invalid arguments or an unknown finding id.
+    pub const USAGE: i32 = 64;
+    /// The root or another required input is missing or unreadable.
+    pub const NO_INPUT: i32 = 66;
+    /// A check panicked or the report could not be produced.
+    pub const INTERNAL: i32 = 70;
+    /// Writing output or another file operation failed.
+    pub const IO: i32 = 74;
+    /// `monorepo-cli.toml` or a profile is invalid.
+    pub const CONFIG: i32 = 78;
+}
+
 #[derive(Debug, Error)]
 pub enum CliError {
+    #[error("root {} is not a readable directory: {source}", path.display())]
+    BadRoot { path: PathBuf, source: io::Error },
+
+    #[error("cannot read {}: {source}", path.display())]
+    Unreadable { path: PathBuf, source: io::Error },
+
+    #[error("config error: {}: {message}", path.display())]
+    ConfigParse { path: PathBuf, message: String },
+
+    #[error("config error: {0}")]
+    Config(String),
+
+    #[error("the {target} check panicked: {message}")]
+    CheckPanicked { target: String, message: String },
+
     #[error("io error: {0}")]
-    Io(#[from] std::io::Error),
+    Io(#[from] io::Error),
+
+    #[error("cannot serialize the report: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("bundle error: {0}")]
+    Bundle(String),
+
+    #[error("scaffold error: {0}")]
+    Scaffold(String),
+
+    #[error("unknown finding id `{0}` (run `check --list` for the known ids)")]
+    UnknownFinding(String),
+}
+
+impl CliError {
+    /// The process exit code for this error; see [`exit`].
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            CliError::BadRoot { .. } | CliError::Unreadable { .. } | CliError::Bundle(_) => {
+                exit::NO_INPUT
+            }
+            CliError::ConfigParse { .. } | CliError::Config(_) => exit::CONFIG,
+            CliError::CheckPanicked { .. } | CliError::Json(_) => exit::INTERNAL,
+            CliError::Io(_) | CliError::Scaffold(_) => exit::IO,
+            CliError::UnknownFinding(_) => exit::USAGE,
+        }
+    }
 }
@@ -0,0 +1,136 @@
+use clap::ValueEnum;
+use monorepo_cli::checks::custom::describe;
+use monorepo_cli::checks::{CheckSpec, FindingDoc, finding_doc};
+use monorepo_cli::config::CustomCheck;
+use monorepo_cli::error::CliError;
+use monorepo_cli::probe;
+use monorepo_cli::{CheckTarget, registry};
+use serde_json::json;
+
+pub fn print_list(custom: &[CustomCheck], as_json: bool) -> Result<(), CliError> {
+    // (target, id, summary) for the built-ins followed by the config-defined checks.
+    let mut rows: Vec<(String, String, String)> = Vec::new();
+    for spec in registry() {
+        for d in spec.findings {
+            rows.push((target_name(spec), d.id.to_string(), d.summary.to_string()));
+        }
+        if spec.target == CheckTarget::Custom {
+            for c in custom {
+                rows.push((target_name(spec), c.id.clone(), c.title.clone()));
+            }
+        }
+    }
+    // `probe` runs against a live deployment rather than through `check`.
+    for d in probe::FINDINGS {
+        rows.push(("probe".to_string(), d.id.to_string(), d.summary.to_string()));
+    }
+
+    if as_json {
+        let ids: Vec<_> = rows
+            .iter()
+            .map(|(target, id, summary)| json!({ "id": id, "target": target, "summary": summary }))
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&ids)?);
+        return Ok(());
+    }
+
+    let width = rows.iter().map(|(_, id, _)| id.len()).max().unwrap_or(0);
+    for spec in registry() {
+        let name = target_name(spec);
+        let scope = if spec.default {
+            ""
+        } else {
+            " (not in `check all` unless the profile adds it)"
+        };
+        println!("{}: {}{}", name, spec.description, scope);
+        for (_, id, summary) in rows.iter().filter(|(t, _, _)| *t == name) {
+            println!("  {id:width$}  {summary}");
+        }
+        println!();
+    }
+    println!("probe: Health of a running deployment (run with `probe --url`)");
+    for (_, id, summary) in rows.iter().filter(|(t, _, _)| t == "probe") {
+        println!("  {id:width$}  {summary}");
+    }
+    Ok(())
+}
+
+// Accepts a finding id, or a target name to explain all of its findings.
+pub fn explain(id: &str, custom: &[CustomCheck]) -> Result<(), CliError> {
+    if let Some((spec, doc)) = finding_doc(id) {
+        print_doc(&target_name(spec), spec.docs, doc);
+        return Ok(());
+    }
+    if let Some(doc) = probe::FINDINGS.iter().find(|d| d.id == id) {
+        print_doc("probe", probe::DOCS, doc);
+        return Ok(());
+    }
+    if let Some(c) = custom.iter().find(|c| c.id == id) {
+        print_custom(c);
+        return Ok(());
+    }
+    if let Some(spec) = registry().iter().find(|s| target_name(s) == id) {
+        for doc in spec.findings {
+            print_doc(&target_name(spec), spec.docs, doc);
+            println!();
+        }
+        return Ok(());
+    }
+    if id == "probe" {
+        for doc in probe::FINDINGS {
+            print_doc("probe", probe::DOCS, doc);
+            println!();
+        }
+        return Ok(());
+    }
+    Err(CliError::UnknownFinding(id.to_string()))
+}
+
+fn print_doc(target: &str, docs: &str, doc: &FindingDoc) {
+    println!("{} — {}", doc.id, doc.summary);
+    println!();
+    println!("Why it matters:");
+    println!("  {}", doc.why);
+    println!();
+    println!("What it inspects:");
+    println!("  {}", doc.inspects);
+    println!();
+    println!("How to fix:");
+    println!("  {}", doc.fix);
+    if let Some(example) = doc.example {
+        println!();
+        println!("Example:");
+        for line in example.lines() {
+            println!("  {line}");
+        }
+    }
+    println!();
+    println!("Target: {target} | docs: {docs}");
+}
+
+fn print_custom(c: &CustomCheck) {
+    println!("{} — {}", c.id, c.title);
+    println!();
+    println!("Declared in the CLI config as a `{}` check.", c.kind.name());
+    println!();
+    println!("What it inspects:");
+    println!("  {}", describe(c));
+    println!();
+    println!("How to fix:");
+    println!(
+        "  {}",
+        c.fix.as_deref().unwrap_or("(no `fix` given in the config)")
+    );
+    println!();
+    println!(
+        "Target: custom | severity: {}",
+        format!("{:?}", c.severity).to_lowercase()
+    );
+}
+
+fn target_name(spec: &CheckSpec) -> String {
+    spec.target
+        .to_possible_value()
+        .map(|v| v.get_name().to_string())
+        .unwrap_or_default()
+}
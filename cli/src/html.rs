@@ -0,0 +1,368 @@
+use std::collections::BTreeSet;
+use std::fmt::{self, Write};
+use std::path::Path;
+
+use crate::i18n::Lang;
+use crate::report::{Finding, Report, Status};
+
+// Everything is inlined so the file can be attached to a ticket and opened offline.
+const STYLE: &str = r#"
+:root { --bg: #ffffff; --fg: #1f2328; --muted: #656d76; --card: #f6f8fa; --border: #d0d7de;
+  --ok: #1a7f37; --warn: #9a6700; --fail: #cf222e; --link: #0969da; }
+:root[data-theme="dark"] { --bg: #0d1117; --fg: #e6edf3; --muted: #8d96a0; --card: #161b22; --border: #30363d;
+  --ok: #3fb950; --warn: #d29922; --fail: #f85149; --link: #4493f8; }
+@media (prefers-color-scheme: dark) {
+  :root:not([data-theme="light"]) { --bg: #0d1117; --fg: #e6edf3; --muted: #8d96a0; --card: #161b22; --border: #30363d;
+    --ok: #3fb950; --warn: #d29922; --fail: #f85149; --link: #4493f8; }
+}
+* { box-sizing: border-box; }
+body { margin: 0 auto; max-width: 1100px; padding: 24px; background: var(--bg); color: var(--fg);
+  font: 14px/1.5 -apple-system, "Segoe UI", Helvetica, Arial, sans-serif; }
+a { color: var(--link); }
+h1 { font-size: 22px; margin: 0 0 12px; }
+code, .mono { font-family: ui-monospace, SFMono-Regular, Menlo, Consolas, monospace; font-size: 13px; }
+header { display: flex; justify-content: space-between; align-items: flex-start; gap: 16px; }
+.run { color: var(--muted); margin: 0; }
+.run dt { float: left; clear: left; width: 110px; }
+.run dd { margin: 0 0 2px 110px; }
+.counts { display: flex; gap: 12px; margin: 20px 0; }
+.count { flex: 1; padding: 12px 16px; border: 1px solid var(--border); border-radius: 8px; background: var(--card); }
+.count b { display: block; font-size: 26px; }
+.filters { display: flex; flex-wrap: wrap; gap: 16px; align-items: center; margin-bottom: 12px; }
+button, select { background: var(--card); color: var(--fg); border: 1px solid var(--border); border-radius: 6px; padding: 4px 10px; }
+details { border: 1px solid var(--border); border-radius: 8px; margin-bottom: 8px; background: var(--card); }
+summary { cursor: pointer; padding: 10px 14px; display: flex; gap: 10px; align-items: baseline; }
+summary .id { margin-left: auto; color: var(--muted); }
+.body { padding: 0 14px 12px 14px; border-top: 1px solid var(--border); }
+.badge { display: inline-block; min-width: 44px; text-align: center; border-radius: 10px; padding: 0 8px;
+  font-size: 12px; font-weight: 600; color: #fff; }
+.s-ok { background: var(--ok); } .s-warn { background: var(--warn); } .s-fail { background: var(--fail); }
+table { border-collapse: collapse; margin-top: 8px; }
+td { padding: 2px 12px 2px 0; vertical-align: top; }
+td:first-child { color: var(--muted); }
+.hidden { display: none; }
+"#;
+
+const SCRIPT: &str = r#"
+const root = document.documentElement;
+const saved = localStorage.getItem("monorepo-cli-theme");
+if (saved) root.dataset.theme = saved;
+document.getElementById("theme").addEventListener("click", () => {
+  const dark = root.dataset.theme ? root.dataset.theme === "dark" : matchMedia("(prefers-color-scheme: dark)").matches;
+  root.dataset.theme = dark ? "light" : "dark";
+  localStorage.setItem("monorepo-cli-theme", root.dataset.theme);
+});
+function applyFilters() {
+  const statuses = [...document.querySelectorAll("input[name=status]:checked")].map((i) => i.value);
+  const category = document.getElementById("category").value;
+  for (const el of document.querySelectorAll("details.finding")) {
+    const show = statuses.includes(el.dataset.status) && (!category || el.dataset.category === category);
+    el.classList.toggle("hidden", !show);
+  }
+}
+document.querySelectorAll("input[name=status], #category").forEach((el) => el.addEventListener("change", applyFilters));
+document.getElementById("expand").addEventListener("click", () => {
+  const open = !document.querySelector("details.finding[open]");
+  document.querySelectorAll("details.finding").forEach((d) => (d.open = open));
+});
+"#;
+
+pub fn render(report: &Report, lang: Lang) -> String {
+    let mut out = String::new();
+    write_html(&mut out, report, lang).expect("writing to a String cannot fail");
+    out
+}
+
+fn write_html(out: &mut String, report: &Report, lang: Lang) -> fmt::Result {
+    let html_lang = match lang {
+        Lang::En => "en",
+        Lang::Ko => "ko",
+    };
+    writeln!(out, "<!DOCTYPE html>")?;
+    writeln!(out, "<html lang=\"{html_lang}\">")?;
+    writeln!(out, "<head>")?;
+    writeln!(out, "<meta charset=\"utf-8\">")?;
+    writeln!(
+        out,
+        "<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">"
+    )?;
+    writeln!(
+        out,
+        "<title>{} — {}</title>",
+        esc(&lang.tr("Readiness report")),
+        esc(&report.profile)
+    )?;
+    writeln!(out, "<style>{STYLE}</style>")?;
+    writeln!(out, "</head>")?;
+    writeln!(out, "<body>")?;
+
+    writeln!(out, "<header>")?;
+    writeln!(out, "<div>")?;
+    writeln!(out, "<h1>{}</h1>", esc(&lang.tr("Readiness report")))?;
+    writeln!(out, "<dl class=\"run\">")?;
+    let run = &report.run;
+    let tools = run
+        .tools
+        .iter()
+        .map(|(name, v)| format!("{name} {}", v.as_deref().unwrap_or("-")))
+        .collect::<Vec<_>>()
+        .join(", ");
+    for (label, value) in [
+        ("Root", report.root.clone()),
+        ("Profile", report.profile.clone()),
+        ("Generated", run.started_at.clone()),
+        ("Duration", format!("{} ms", run.duration_ms)),
+        ("Git commit", git_summary(report)),
+        (
+            "Host",
+            format!(
+                "{} ({} {})",
+                run.hostname.as_deref().unwrap_or("-"),
+                run.os,
+                run.arch
+            ),
+        ),
+        ("Tools", tools),
+        (
+            "CLI",
+            format!("{} (schema {})", run.cli_version, report.schema_version),
+        ),
+    ] {
+        writeln!(
+            out,
+            "<dt>{}</dt><dd class=\"mono\">{}</dd>",
+            esc(&lang.tr(label)),
+            esc(&value)
+        )?;
+    }
+    writeln!(out, "</dl>")?;
+    writeln!(out, "</div>")?;
+    writeln!(
+        out,
+        "<button id=\"theme\" type=\"button\">{}</button>",
+        esc(&lang.tr("Toggle theme"))
+    )?;
+    writeln!(out, "</header>")?;
+
+    writeln!(out, "<section class=\"counts\">")?;
+    for status in [Status::Ok, Status::Warn, Status::Fail] {
+        let key = status_key(status);
+        writeln!(
+            out,
+            "<div class=\"count\"><span class=\"badge s-{key}\">{key}</span><b>{}</b></div>",
+            report.counts.get(key).copied().unwrap_or(0)
+        )?;
+    }
+    writeln!(out, "</section>")?;
+
+    writeln!(out, "<section class=\"filters\">")?;
+    for status in [Status::Ok, Status::Warn, Status::Fail] {
+        let key = status_key(status);
+        writeln!(
+            out,
+            "<label><input type=\"checkbox\" name=\"status\" value=\"{key}\" checked> <span class=\"badge s-{key}\">{key}</span></label>"
+        )?;
+    }
+    let categories: BTreeSet<&str> = report
+        .findings
+        .iter()
+        .map(|f| f.category.as_str())
+        .collect();
+    writeln!(
+        out,
+        "<label>{} <select id=\"category\"><option value=\"\">{}</option>",
+        esc(&lang.tr("Category")),
+        esc(&lang.tr("All"))
+    )?;
+    for c in categories {
+        writeln!(out, "<option value=\"{0}\">{0}</option>", esc(c))?;
+    }
+    writeln!(out, "</select></label>")?;
+    writeln!(
+        out,
+        "<button id=\"expand\" type=\"button\">{}</button>",
+        esc(&lang.tr("Expand / collapse all"))
+    )?;
+    writeln!(out, "</section>")?;
+
+    writeln!(out, "<main>")?;
+    for f in &report.findings {
+        write_finding(out, report, f, lang)?;
+    }
+    writeln!(out, "</main>")?;
+
+    writeln!(out, "<script>{SCRIPT}</script>")?;
+    writeln!(out, "</body>")?;
+    writeln!(out, "</html>")?;
+    Ok(())
+}
+
+fn write_finding(out: &mut String, report: &Report, f: &Finding, lang: Lang) -> fmt::Result {
+    let key = status_key(f.status);
+    // Problems start expanded so a reviewer sees them without clicking.
+    let open = if f.status == Status::Ok { "" } else { " open" };
+    writeln!(
+        out,
+        "<details class=\"finding\" data-status=\"{key}\" data-category=\"{}\"{open}>",
+        esc(&f.category)
+    )?;
+    writeln!(
+        out,
+        "<summary><span class=\"badge s-{key}\">{key}</span><span>{}</span><code class=\"id\">{}</code></summary>",
+        esc(&f.title),
+        esc(&f.id)
+    )?;
+    writeln!(out, "<div class=\"body\">")?;
+    writeln!(out, "<p>{}</p>", esc(&f.details))?;
+    writeln!(out, "<table>")?;
+    if let Some(loc) = &f.location {
+        let path = Path::new(&report.root).join(&loc.path);
+        writeln!(
+            out,
+            "<tr><td>{}</td><td><a class=\"mono\" href=\"{}\">{}</a></td></tr>",
+            esc(&lang.tr("Location")),
+            esc(&file_url(&path.to_string_lossy())),
+            esc(&loc.to_string())
+        )?;
+    }
+    if let Some(fix) = &f.remediation {
+        writeln!(
+            out,
+            "<tr><td>{}</td><td>{}</td></tr>",
+            esc(&lang.tr("fix")),
+            esc(fix)
+        )?;
+    }
+    if let Some(docs) = &f.docs {
+        let href = if docs.starts_with("http://") || docs.starts_with("https://") {
+            docs.clone()
+        } else {
+            // The anchor stays a fragment rather than part of the file name.
+            let (file, anchor) = docs.split_once('#').unwrap_or((docs, ""));
+            let mut url = file_url(&Path::new(&report.root).join(file).to_string_lossy());
+            if !anchor.is_empty() {
+                url.push('#');
+                url.push_str(&percent_encode(anchor));
+            }
+            url
+        };
+        writeln!(
+            out,
+            "<tr><td>{}</td><td><a href=\"{}\">{}</a></td></tr>",
+            esc(&lang.tr("docs")),
+            esc(&href),
+            esc(docs)
+        )?;
+    }
+    writeln!(
+        out,
+        "<tr><td>{}</td><td>{}</td></tr>",
+        esc(&lang.tr("category")),
+        esc(&f.category)
+    )?;
+    if !f.tags.is_empty() {
+        writeln!(
+            out,
+            "<tr><td>{}</td><td>{}</td></tr>",
+            esc(&lang.tr("tags")),
+            esc(&f.tags.join(", "))
+        )?;
+    }
+    for (k, v) in &f.meta {
+        writeln!(
+            out,
+            "<tr><td class=\"mono\">{}</td><td class=\"mono\">{}</td></tr>",
+            esc(k),
+            esc(v)
+        )?;
+    }
+    writeln!(out, "</table>")?;
+    writeln!(out, "</div>")?;
+    writeln!(out, "</details>")?;
+    Ok(())
+}
+
+fn git_summary(report: &Report) -> String {
+    let Some(git) = &report.run.git else {
+        return "-".to_string();
+    };
+    let mut s = match &git.branch {
+        Some(branch) => format!("{} ({branch})", git.commit),
+        None => format!("{} (detached)", git.commit),
+    };
+    if git.dirty == Some(true) {
+        s.push_str(" +dirty");
+    }
+    s
+}
+
+fn status_key(status: Status) -> &'static str {
+    match status {
+        Status::Ok => "ok",
+        Status::Warn => "warn",
+        Status::Fail => "fail",
+    }
+}
+
+// `file://` URL for an absolute path, each segment percent-encoded so names with `#`, `%`, `?` or
+// spaces stay part of the path.
+fn file_url(path: &str) -> String {
+    let path = path.replace('\\', "/");
+    let segments: Vec<String> = path
+        .trim_start_matches('/')
+        .split('/')
+        .map(percent_encode)
+        .collect();
+    format!("file:///{}", segments.join("/"))
+}
+
+// Everything but unreserved characters and the `:`/`@` a path segment may hold (`C:`).
+fn percent_encode(segment: &str) -> String {
+    let mut out = String::with_capacity(segment.len());
+    for b in segment.bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b':' | b'@' => {
+                out.push(b as char)
+            }
+            _ => out.push_str(&format!("%{b:02X}")),
+        }
+    }
+    out
+}
+
+fn esc(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn file_urls_encode_each_segment() {
+        assert_eq!(
+            file_url("/srv/my repo/docs/a#b%c?.md"),
+            "file:///srv/my%20repo/docs/a%23b%25c%3F.md"
+        );
+        assert_eq!(
+            file_url("/home/데브/x.md"),
+            "file:///home/%EB%8D%B0%EB%B8%8C/x.md"
+        );
+        assert_eq!(
+            file_url("C:\\Users\\dev\\README.md"),
+            "file:///C:/Users/dev/README.md"
+        );
+        assert_eq!(file_url("/a/b-c_d.e~f"), "file:///a/b-c_d.e~f");
+    }
+}
@@ -0,0 +1,284 @@
+use clap::ValueEnum;
+use regex::Regex;
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+
+use crate::report::Report;
+
+const KO: &str = include_str!("../locales/ko.toml");
+
+/// Language of titles, details and remediation text. Ids and JSON keys are never translated.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum Lang {
+    #[default]
+    En,
+    Ko,
+}
+
+impl Lang {
+    /// Picks the language from `LC_ALL`, `LC_MESSAGES` or `LANG` (e.g. `ko_KR.UTF-8`).
+    pub fn from_env() -> Lang {
+        let locale = ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|k| std::env::var(k).ok())
+            .find(|v| !v.is_empty())
+            .unwrap_or_default();
+        if locale.to_lowercase().starts_with("ko") {
+            Lang::Ko
+        } else {
+            Lang::En
+        }
+    }
+
+    /// Translates an English message, returning it unchanged when the catalog has no entry.
+    pub fn tr(self, msg: &str) -> String {
+        match self {
+            Lang::En => msg.to_string(),
+            Lang::Ko => catalog_ko().translate(msg),
+        }
+    }
+}
+
+/// Translates every finding's title, details and remediation in place.
+pub fn localize(report: &mut Report, lang: Lang) {
+    if lang == Lang::En {
+        return;
+    }
+    for f in &mut report.findings {
+        f.title = lang.tr(&f.title);
+        f.details = lang.tr(&f.details);
+        if let Some(fix) = &f.remediation {
+            f.remediation = Some(lang.tr(fix));
+        }
+    }
+}
+
+struct Catalog {
+    exact: BTreeMap<String, String>,
+    // Messages built with format!: `{}` in the key matches any text.
+    templates: Vec<(Regex, String)>,
+}
+
+impl Catalog {
+    fn parse(raw: &str) -> Catalog {
+        let entries: BTreeMap<String, String> = toml::from_str(raw).expect("valid message catalog");
+        let mut exact = BTreeMap::new();
+        let mut templates = Vec::new();
+        for (en, tr) in entries {
+            if en.contains("{}") {
+                let pattern = en
+                    .split("{}")
+                    .map(regex::escape)
+                    .collect::<Vec<_>>()
+                    .join("(.*?)");
+                let re = Regex::new(&format!("^{pattern}$")).expect("valid template pattern");
+                templates.push((re, tr));
+            } else {
+                exact.insert(en, tr);
+            }
+        }
+        Catalog { exact, templates }
+    }
+
+    fn translate(&self, msg: &str) -> String {
+        // A template's `{}` also matches `, `, so a list of problems is first tried item by item;
+        // a message that only contains a comma (`allows a, b`) is matched as a whole.
+        self.translate_list(msg, true)
+            .or_else(|| self.lookup(msg))
+            .or_else(|| self.translate_list(msg, false))
+            .unwrap_or_else(|| msg.to_string())
+    }
+
+    fn lookup(&self, msg: &str) -> Option<String> {
+        if let Some(tr) = self.exact.get(msg) {
+            return Some(tr.clone());
+        }
+        for (re, tr) in &self.templates {
+            if let Some(caps) = re.captures(msg) {
+                let args: Vec<&str> = caps
+                    .iter()
+                    .skip(1)
+                    .map(|m| m.map_or("", |m| m.as_str()))
+                    .collect();
+                return Some(substitute(tr, &args));
+            }
+        }
+        None
+    }
+
+    // Details that list several problems (`a, b (+3 more)`, see `checks::listed`), translated
+    // item by item; `None` unless every item (with `all`) or at least one item has an entry.
+    fn translate_list(&self, msg: &str, all: bool) -> Option<String> {
+        let (items, more) = match msg.rsplit_once(" (+") {
+            Some((items, rest)) if rest.ends_with(" more)") => {
+                (items, Some(&msg[items.len() + 1..]))
+            }
+            _ => (msg, None),
+        };
+        if !items.contains(", ") && more.is_none() {
+            return None;
+        }
+        let mut translated = false;
+        let mut untranslated = false;
+        let mut out = items
+            .split(", ")
+            .map(|item| {
+                let tr = self.lookup(item);
+                translated |= tr.is_some();
+                untranslated |= tr.is_none();
+                tr.unwrap_or_else(|| item.to_string())
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        if !translated || (all && untranslated) {
+            return None;
+        }
+        if let Some(more) = more {
+            out.push(' ');
+            out.push_str(&self.lookup(more).unwrap_or_else(|| more.to_string()));
+        }
+        Some(out)
+    }
+}
+
+// Fills a translation like `format!` does: `{0}`, `{1}`, ... pick an English placeholder by
+// position so the sentence can be reordered, and each plain `{}` takes the next one in order.
+// An index with no matching placeholder is left as written so the catalog mistake shows.
+fn substitute(tr: &str, args: &[&str]) -> String {
+    let mut out = String::with_capacity(tr.len());
+    let mut next = 0;
+    let mut rest = tr;
+    while let Some(open) = rest.find('{') {
+        out.push_str(&rest[..open]);
+        rest = &rest[open..];
+        let Some(close) = rest.find('}') else { break };
+        let inner = &rest[1..close];
+        let index = if inner.is_empty() {
+            next += 1;
+            Some(next - 1)
+        } else {
+            inner.parse::<usize>().ok()
+        };
+        match index.and_then(|i| args.get(i)) {
+            Some(arg) => out.push_str(arg),
+            None => out.push_str(&rest[..=close]),
+        }
+        rest = &rest[close + 1..];
+    }
+    out.push_str(rest);
+    out
+}
+
+fn catalog_ko() -> &'static Catalog {
+    static CATALOG: OnceLock<Catalog> = OnceLock::new();
+    CATALOG.get_or_init(|| Catalog::parse(KO))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn catalog(raw: &str) -> Catalog {
+        Catalog::parse(raw)
+    }
+
+    #[test]
+    fn lists_are_translated_item_by_item() {
+        let c = catalog(
+            r#"
+"{} is not registered" = "{}은(는) 등록되어 있지 않습니다"
+"(+{} more)" = "(외 {}개)"
+"#,
+        );
+        assert_eq!(
+            c.translate("a.ts:1 foo is not registered, b.ts:2 bar is not registered"),
+            "a.ts:1 foo은(는) 등록되어 있지 않습니다, b.ts:2 bar은(는) 등록되어 있지 않습니다"
+        );
+        assert_eq!(
+            c.translate("a.ts:1 foo is not registered (+3 more)"),
+            "a.ts:1 foo은(는) 등록되어 있지 않습니다 (외 3개)"
+        );
+    }
+
+    #[test]
+    fn a_comma_inside_one_message_is_not_a_list() {
+        let c = catalog(
+            r#"
+"script-src allows {}" = "script-src가 {}을(를) 허용합니다"
+"#,
+        );
+        assert_eq!(
+            c.translate("script-src allows 'unsafe-eval', 'unsafe-inline'"),
+            "script-src가 'unsafe-eval', 'unsafe-inline'을(를) 허용합니다"
+        );
+    }
+
+    #[test]
+    fn partially_known_lists_translate_the_known_items() {
+        let c = catalog(
+            r#"
+"{} is never invoked" = "{}은(는) 호출되지 않습니다"
+"#,
+        );
+        assert_eq!(
+            c.translate("x is never invoked, something else"),
+            "x은(는) 호출되지 않습니다, something else"
+        );
+        assert_eq!(c.translate("a, b"), "a, b");
+    }
+
+    #[test]
+    fn positional_and_plain_placeholders() {
+        assert_eq!(substitute("{1} / {0}", &["a", "b"]), "b / a");
+        assert_eq!(substitute("{} / {}", &["a", "b"]), "a / b");
+        // As with `format!`, plain `{}` count on their own regardless of explicit indices.
+        assert_eq!(substitute("{1}: {} {}", &["a", "b"]), "b: a b");
+        assert_eq!(substitute("{0} {0}", &["a"]), "a a");
+        assert_eq!(substitute("{2} {} {x} {", &["a"]), "{2} a {x} {");
+        assert_eq!(substitute("no placeholders", &["a"]), "no placeholders");
+    }
+
+    #[test]
+    fn templates_reorder_arguments() {
+        let c = catalog(
+            r#"
+"{} pins {} for {}" = "{2}에서 {0}이(가) {1}을(를) 고정합니다"
+"{} needs {}" = "{}에 {}이(가) 필요합니다"
+"{} is missing" = "{}이(가) 없습니다"
+"exact {} text" = "정확한 {}"
+"exact {x} text" = "중괄호 {x}"
+"#,
+        );
+        assert_eq!(
+            c.translate("rust-toolchain.toml pins 1.80 for be"),
+            "be에서 rust-toolchain.toml이(가) 1.80을(를) 고정합니다"
+        );
+        assert_eq!(c.translate("fe needs bun"), "fe에 bun이(가) 필요합니다");
+        assert_eq!(c.translate(" is missing"), "이(가) 없습니다");
+        // Keys without `{}` only match exactly, even when they contain braces.
+        assert_eq!(c.translate("exact {x} text"), "중괄호 {x}");
+        assert_eq!(c.translate("exact y text"), "정확한 y");
+        assert_eq!(c.translate("unknown"), "unknown");
+    }
+
+    #[test]
+    fn korean_catalog_placeholders_match_the_english_keys() {
+        let entries: BTreeMap<String, String> = toml::from_str(KO).unwrap();
+        let index = Regex::new(r"\{(\d+)\}").unwrap();
+        for (en, tr) in entries {
+            let args = en.matches("{}").count();
+            let mut used: Vec<usize> = index
+                .captures_iter(&tr)
+                .map(|c| c[1].parse().unwrap())
+                .chain(0..tr.matches("{}").count())
+                .collect();
+            used.sort();
+            used.dedup();
+            assert_eq!(
+                used,
+                (0..args).collect::<Vec<_>>(),
+                "{en:?}: {tr:?} should use each placeholder of the key"
+            );
+        }
+    }
+}
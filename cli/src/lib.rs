@@ -0,0 +1,36 @@
+//! Production readiness checks for the monorepo template.
+//!
+//! The `cli` binary is a thin wrapper around this crate; other tools (such as the
+//! `gui-edit` Tauri app) can embed the same checks:
+//!
+//! ```no_run
+//! use monorepo_cli::{Options, Status, run_checks};
+//!
+//! let opts = Options::with_profile("..", "prod", None).expect("valid profile");
+//! let report = run_checks(&opts).expect("readable root");
+//! let failed = report.findings.iter().filter(|f| f.status == Status::Fail).count();
+//! println!("{failed} failing check(s)");
+//! ```
+
+pub mod bundle;
+pub mod checks;
+pub mod config;
+pub mod error;
+pub mod html;
+pub mod i18n;
+pub mod probe;
+pub mod profile;
+pub mod prometheus;
+pub mod report;
+pub mod run;
+pub mod scaffold;
+pub mod util;
+
+pub use checks::{
+    CheckSpec, CheckTarget, Context, FindingDoc, Options, finding_doc, registry, run_checks,
+};
+pub use config::Config;
+pub use error::CliError;
+pub use i18n::Lang;
+pub use profile::Profile;
+pub use report::{Finding, GitInfo, Location, Report, Run, SCHEMA_VERSION, Status};
@@ -1,29 +1,181 @@
 mod args;
-mod checks;
-mod error;
-mod report;
-mod util;
+mod explain;
 
 use clap::Parser;
+use std::io::Write;
+use std::path::Path;
+use std::time::Duration;
 
-use args::{Cli, Commands};
-use report::{exit_code, print_human_report};
+use args::{BundleCommand, Cli, Commands, OutputFormat, ScaffoldCommand};
+use monorepo_cli::error::{CliError, exit};
+use monorepo_cli::probe::{ProbeOptions, run_probe};
+use monorepo_cli::report::{Report, exit_code, human_report};
+use monorepo_cli::scaffold::SystemdOptions;
+use monorepo_cli::util::env::backend_ports;
+use monorepo_cli::{Config, Lang, Options, run_checks};
+use monorepo_cli::{bundle, html, prometheus, scaffold};
 
-fn main() -> Result<(), error::CliError> {
-    let cli = Cli::parse();
+fn main() {
+    let cli = Cli::try_parse().unwrap_or_else(|e| {
+        // `--help` and `--version` arrive here too and keep their zero exit code.
+        let code = if e.use_stderr() {
+            exit::USAGE
+        } else {
+            exit::OK
+        };
+        let _ = e.print();
+        std::process::exit(code);
+    });
+    if let Err(e) = run(cli) {
+        eprintln!("error: {e}");
+        std::process::exit(e.exit_code());
+    }
+}
+
+fn run(cli: Cli) -> Result<(), CliError> {
+    let lang = cli.lang.unwrap_or_else(Lang::from_env);
 
     match cli.command {
+        Commands::Check(args) if args.list => {
+            let config = Config::load(&args.root, args.config.as_deref())?;
+            let as_json = args.json || args.format == OutputFormat::Json;
+            explain::print_list(&config.checks, as_json)?;
+        }
         Commands::Check(args) => {
-            let report = checks::run_checks(&args)?;
+            let mut opts =
+                Options::with_profile(&args.root, &args.profile, args.config.as_deref())?;
+            opts.targets = args.targets;
+            opts.lang = lang;
+            opts.skip = args.skip;
+            opts.min_status = args.min_status;
+            let report = run_checks(&opts)?;
 
-            if args.json {
-                println!("{}", serde_json::to_string_pretty(&report).unwrap());
+            let format = if args.json {
+                OutputFormat::Json
             } else {
-                print_human_report(&report);
+                args.format
+            };
+            let gate = Gate {
+                strict: args.strict,
+                fail_on: &args.fail_on,
+            };
+            emit(&report, format, args.out.as_deref(), gate, lang)?;
+        }
+        Commands::Bundle(args) => match args.command {
+            Some(BundleCommand::Verify(v)) => {
+                let result = bundle::verify(&v.archive)?;
+                let m = &result.manifest;
+                println!(
+                    "{}: {} file(s), created {} by cli {}",
+                    v.archive.display(),
+                    m.files.len(),
+                    m.created_at,
+                    m.cli_version
+                );
+                if result.is_ok() {
+                    println!("OK: every file matches its SHA-256 checksum");
+                } else {
+                    for p in &result.problems {
+                        println!("  - {p}");
+                    }
+                    std::process::exit(exit::FAIL);
+                }
             }
+            None => {
+                let opts =
+                    Options::with_profile(&args.root, &args.profile, args.config.as_deref())?;
+                let report = run_checks(&opts)?;
+                let failed = report.counts.get("fail").copied().unwrap_or(0);
+                if failed > 0 {
+                    print!("{}", human_report(&report, lang));
+                    eprintln!("Refusing to bundle: {failed} check(s) failed");
+                    std::process::exit(exit::FAIL);
+                }
+                let manifest = bundle::create(Path::new(&report.root), &args.out, &report)?;
+                println!(
+                    "Wrote {} ({} file(s), readiness ok={} warn={})",
+                    args.out.display(),
+                    manifest.files.len(),
+                    report.counts.get("ok").copied().unwrap_or(0),
+                    report.counts.get("warn").copied().unwrap_or(0)
+                );
+            }
+        },
+        Commands::Scaffold(ScaffoldCommand::Systemd(args)) => {
+            let opts = Options::with_profile(&args.root, &args.profile, args.config.as_deref())?;
+            let env_file = opts.root.join("apps/be").join(&opts.profile.env_file);
+            let (port, admin_port) = backend_ports(&opts.root, &env_file);
+            let units = SystemdOptions {
+                install_dir: args.install_dir,
+                user: args.user,
+                bun: args.bun,
+                port,
+                admin_port,
+            };
+            for path in scaffold::write_systemd_units(&opts.root, &units, args.force)? {
+                println!("Wrote {}", path.display());
+            }
+        }
+        Commands::Probe(args) => {
+            let base = Options::with_profile(&args.root, &args.profile, args.config.as_deref())?;
+            let mut opts = ProbeOptions::new(args.url, base.root);
+            opts.admin_url = args.admin_url;
+            opts.profile = base.profile;
+            opts.attempts = args.retries;
+            opts.timeout = Duration::from_secs(args.timeout);
+            opts.lang = lang;
+            let report = run_probe(&opts);
+
+            let format = if args.json {
+                OutputFormat::Json
+            } else {
+                args.format
+            };
+            let gate = Gate {
+                strict: args.strict,
+                fail_on: &[],
+            };
+            emit(&report, format, args.out.as_deref(), gate, lang)?;
+        }
+        Commands::Explain(args) => {
+            let config = Config::load(&args.root, args.config.as_deref())?;
+            explain::explain(&args.id, &config.checks)?;
+        }
+    }
 
-            let exit = exit_code(&report, args.strict);
-            std::process::exit(exit);
+    Ok(())
+}
+
+// Which findings decide the exit code.
+struct Gate<'a> {
+    strict: bool,
+    fail_on: &'a [String],
+}
+
+// Renders a report to stdout or `out`, then exits with the report's exit code.
+fn emit(
+    report: &Report,
+    format: OutputFormat,
+    out: Option<&Path>,
+    gate: Gate,
+    lang: Lang,
+) -> Result<(), CliError> {
+    let rendered = match format {
+        OutputFormat::Human => human_report(report, lang),
+        OutputFormat::Json => {
+            format!("{}\n", serde_json::to_string_pretty(report)?)
         }
+        OutputFormat::Html => html::render(report, lang),
+        OutputFormat::Prometheus => prometheus::render(report),
+    };
+    match out {
+        // Atomic, so a textfile collector or browser never picks up a half-written report.
+        Some(path) => bundle::write_atomically(path, |mut file| {
+            file.write_all(rendered.as_bytes())?;
+            Ok(())
+        })?,
+        None => print!("{rendered}"),
     }
+
+    std::process::exit(exit_code(report, gate.strict, gate.fail_on));
 }
@@ -0,0 +1,377 @@
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
+
+use crate::checks::FindingDoc;
+use crate::i18n::{Lang, localize};
+use crate::profile::Profile;
+use crate::report::{Finding, Report, SCHEMA_VERSION, Status, counts, finding};
+use crate::run;
+use crate::util::env::backend_ports;
+
+pub const DOCS: &str = "docs/architecture.md#backend-process-types";
+
+pub const FINDINGS: &[FindingDoc] = &[
+    FindingDoc {
+        id: "probe.health",
+        summary: "The main server answers /health with 200 OK",
+        why: "`pm2 start` succeeding only means the process was spawned; the health route proves the server booted, loaded its routes and is listening.",
+        inspects: "`GET <url>/health` (apps/be/src/routes/health.ts), retried with exponential backoff while the server starts. Status 200 and the body `OK` are required; the latency of the successful attempt is recorded.",
+        fix: "Check the process logs (`pm2 logs`), the PORT in the env file and that the URL points at the main process.",
+        example: None,
+    },
+    FindingDoc {
+        id: "probe.admin_health",
+        summary: "The admin server answers /health with 200 OK",
+        why: "The admin process runs separately on ADMIN_PORT and can be down while the main server is healthy.",
+        inspects: "`GET <admin-url>/health` (apps/be/src/routes-admin/health.ts). Without `--admin-url` the main URL is reused with ADMIN_PORT, or with an `/admin` prefix when the URL has no port (nginx in front).",
+        fix: "Start the admin process (PROCESS_TYPE=admin) or pass the right `--admin-url`.",
+        example: None,
+    },
+    FindingDoc {
+        id: "probe.openapi",
+        summary: "The OpenAPI document is served in dev and hidden otherwise",
+        why: "Codegen and the Swagger UI read `/openapi/json` in development; in production the app disables it so the API surface is not published.",
+        inspects: "`GET <url>/openapi/json`: under `dev` and profiles extending it it must return a JSON document with an `openapi` field; under `prod` and profiles extending it it should not answer 200.",
+        fix: "In dev, start the server with NODE_ENV unset or `development`; in production make sure NODE_ENV=production.",
+        example: None,
+    },
+];
+
+/// Where and how persistently to probe.
+#[derive(Debug, Clone)]
+pub struct ProbeOptions {
+    /// Base URL of the main server, e.g. `http://localhost:8000`.
+    pub url: String,
+    /// Base URL of the admin server; derived from `url` when `None`.
+    pub admin_url: Option<String>,
+    /// Repo root, used for ADMIN_PORT and the run metadata.
+    pub root: PathBuf,
+    pub profile: Profile,
+    /// Attempts per endpoint before giving up.
+    pub attempts: u32,
+    /// Delay before the first retry; doubled after each failed attempt.
+    pub backoff: Duration,
+    pub timeout: Duration,
+    pub lang: Lang,
+}
+
+impl ProbeOptions {
+    pub fn new(url: impl Into<String>, root: impl Into<PathBuf>) -> ProbeOptions {
+        ProbeOptions {
+            url: url.into(),
+            admin_url: None,
+            root: root.into(),
+            profile: Profile::dev(),
+            attempts: 5,
+            backoff: Duration::from_millis(250),
+            timeout: Duration::from_secs(5),
+            lang: Lang::En,
+        }
+    }
+
+    fn admin_url(&self) -> String {
+        if let Some(url) = &self.admin_url {
+            return url.trim_end_matches('/').to_string();
+        }
+        let env_file = self.root.join("apps/be").join(&self.profile.env_file);
+        let (port, admin_port) = backend_ports(&self.root, &env_file);
+        let base = self.url.trim_end_matches('/');
+        let (scheme, rest) = base.split_once("://").unwrap_or(("http", base));
+        let (authority, path) = rest.split_at(rest.find('/').unwrap_or(rest.len()));
+        match authority.rsplit_once(':') {
+            Some((host, p)) if p.parse::<u16>().is_ok() => {
+                // Keep other ports as given: only the backend's own PORT maps to ADMIN_PORT.
+                let p = if p == port.to_string() {
+                    admin_port.to_string()
+                } else {
+                    p.to_string()
+                };
+                format!("{scheme}://{host}:{p}{path}")
+            }
+            _ => format!("{base}/admin"),
+        }
+    }
+}
+
+// Outcome of the last attempt against one endpoint.
+struct Attempt {
+    status: Option<u16>,
+    body: String,
+    error: Option<String>,
+    latency: Duration,
+    attempts: u32,
+}
+
+/// Probes a running deployment and reports the results like a check run.
+pub fn run_probe(opts: &ProbeOptions) -> Report {
+    let started = SystemTime::now();
+    let timer = Instant::now();
+    let agent: ureq::Agent = ureq::Agent::config_builder()
+        .timeout_global(Some(opts.timeout))
+        .http_status_as_error(false)
+        .build()
+        .into();
+
+    let base = opts.url.trim_end_matches('/');
+    let admin = opts.admin_url();
+    let mut findings = vec![
+        health(
+            &agent,
+            opts,
+            "probe.health",
+            "Main server /health returns OK",
+            base,
+        ),
+        health(
+            &agent,
+            opts,
+            "probe.admin_health",
+            "Admin server /health returns OK",
+            &admin,
+        ),
+        openapi(&agent, opts, base),
+    ];
+
+    for f in &mut findings {
+        f.docs.get_or_insert_with(|| DOCS.to_string());
+        opts.profile.adjust(f);
+    }
+
+    let root = opts.root.canonicalize().unwrap_or(opts.root.clone());
+    let mut report = Report {
+        schema_version: SCHEMA_VERSION,
+        root: root.to_string_lossy().to_string(),
+        profile: opts.profile.name.clone(),
+        run: run::collect(&root, started, timer.elapsed()),
+        counts: counts(&findings),
+        findings,
+    };
+    localize(&mut report, opts.lang);
+    report
+}
+
+fn health(agent: &ureq::Agent, opts: &ProbeOptions, id: &str, title: &str, base: &str) -> Finding {
+    let url = format!("{base}/health");
+    let a = get(agent, opts, &url, |status, body| {
+        status == 200 && body.trim() == "OK"
+    });
+    let f = match (&a.error, a.status) {
+        (None, Some(200)) if a.body.trim() == "OK" => finding(
+            id,
+            title,
+            Status::Ok,
+            format!("200 OK in {} ms", a.latency.as_millis()),
+        ),
+        (None, Some(200)) => finding(
+            id,
+            title,
+            Status::Fail,
+            format!("200 with body `{}`, expected `OK`", excerpt(&a.body)),
+        )
+        .fix("Make sure the URL points at this backend and not another service"),
+        (None, Some(status)) => finding(
+            id,
+            title,
+            Status::Fail,
+            format!("HTTP {status} after {} attempt(s)", a.attempts),
+        )
+        .fix("Check the process logs (pm2 logs) and the nginx proxy for this route"),
+        (Some(e), _) => finding(
+            id,
+            title,
+            Status::Fail,
+            format!("{e} after {} attempt(s)", a.attempts),
+        )
+        .fix("Start the process and check that it listens on this URL"),
+        (None, None) => unreachable!("a response without a status"),
+    };
+    with_meta(f, &url, &a)
+}
+
+fn openapi(agent: &ureq::Agent, opts: &ProbeOptions, base: &str) -> Finding {
+    let url = format!("{base}/openapi/json");
+    let title = "OpenAPI document is served only in dev";
+    let is_spec = |status: u16, body: &str| {
+        status == 200
+            && serde_json::from_str::<serde_json::Value>(body)
+                .is_ok_and(|v| v.get("openapi").is_some())
+    };
+
+    if !opts.profile.serves_openapi {
+        // Hidden is the expected state, so a single attempt is enough.
+        let single = ProbeOptions {
+            attempts: 1,
+            ..opts.clone()
+        };
+        let a = get(agent, &single, &url, |_, _| true);
+        let f = match (&a.error, a.status) {
+            (None, Some(status)) if is_spec(status, &a.body) => finding(
+                "probe.openapi",
+                title,
+                Status::Warn,
+                "The OpenAPI document is publicly reachable",
+            )
+            .fix("Run the server with NODE_ENV=production so openapi/swagger are disabled"),
+            // Answered, but whether with the document is unknown.
+            (Some(e), Some(_)) => finding("probe.openapi", title, Status::Warn, e.clone())
+                .fix("Check the process logs (pm2 logs) and the nginx proxy for this route"),
+            _ => finding("probe.openapi", title, Status::Ok, "Not served outside dev"),
+        };
+        return with_meta(f, &url, &a);
+    }
+
+    let a = get(agent, opts, &url, is_spec);
+    let f = match (&a.error, a.status) {
+        (None, Some(status)) if is_spec(status, &a.body) => finding(
+            "probe.openapi",
+            title,
+            Status::Ok,
+            format!("Served in {} ms", a.latency.as_millis()),
+        ),
+        (None, Some(status)) => finding(
+            "probe.openapi",
+            title,
+            Status::Warn,
+            format!("HTTP {status} without an OpenAPI document"),
+        )
+        .fix("Start the main server in development mode (enableSwagger is on for main)"),
+        (Some(e), _) => finding(
+            "probe.openapi",
+            title,
+            Status::Warn,
+            format!("{e} after {} attempt(s)", a.attempts),
+        )
+        .fix("Start the main server in development mode"),
+        (None, None) => unreachable!("a response without a status"),
+    };
+    with_meta(f, &url, &a)
+}
+
+// Retries until `done` accepts the response or the attempts run out, doubling the delay each time.
+fn get(
+    agent: &ureq::Agent,
+    opts: &ProbeOptions,
+    url: &str,
+    done: impl Fn(u16, &str) -> bool,
+) -> Attempt {
+    let delays = backoff(opts.backoff, opts.attempts);
+    let attempts = delays.len() as u32 + 1;
+    let mut last = None;
+    for n in 1..=attempts {
+        let t = Instant::now();
+        let attempt = match agent.get(url).call() {
+            Ok(mut resp) => {
+                let status = resp.status().as_u16();
+                let (body, error) = match resp.body_mut().read_to_string() {
+                    Ok(body) => (body, None),
+                    Err(e) => (
+                        String::new(),
+                        Some(format!("HTTP {status} with an unreadable body ({e})")),
+                    ),
+                };
+                Attempt {
+                    status: Some(status),
+                    body,
+                    error,
+                    latency: t.elapsed(),
+                    attempts: n,
+                }
+            }
+            Err(e) => Attempt {
+                status: None,
+                body: String::new(),
+                error: Some(e.to_string()),
+                latency: t.elapsed(),
+                attempts: n,
+            },
+        };
+        if attempt.error.is_none() && attempt.status.is_some_and(|s| done(s, &attempt.body)) {
+            return attempt;
+        }
+        last = Some(attempt);
+        if let Some(delay) = delays.get(n as usize - 1) {
+            thread::sleep(*delay);
+        }
+    }
+    last.expect("at least one attempt")
+}
+
+// Sleeps between `attempts` (at least one) tries: `first`, then doubled each time.
+fn backoff(first: Duration, attempts: u32) -> Vec<Duration> {
+    (0..attempts.max(1) - 1)
+        .map(|n| first.saturating_mul(1 << n.min(31)))
+        .collect()
+}
+
+fn with_meta(f: Finding, url: &str, a: &Attempt) -> Finding {
+    let f = f
+        .meta("url", url)
+        .meta("attempts", a.attempts.to_string())
+        .meta("latency_ms", a.latency.as_millis().to_string());
+    match a.status {
+        Some(s) => f.meta("status", s.to_string()),
+        None => f,
+    }
+}
+
+fn excerpt(body: &str) -> String {
+    let body = body.trim();
+    match body.char_indices().nth(60) {
+        Some((i, _)) => format!("{}…", &body[..i]),
+        None => body.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn opts(url: &str, name: &str) -> ProbeOptions {
+        let root = std::env::temp_dir().join(format!("probe-{name}-{}", std::process::id()));
+        fs::create_dir_all(root.join("apps/be")).unwrap();
+        fs::write(root.join("apps/be/.env"), "PORT=9000\nADMIN_PORT=9001\n").unwrap();
+        ProbeOptions::new(url, root)
+    }
+
+    #[test]
+    fn admin_url_maps_the_backend_port() {
+        let cases = [
+            ("http://localhost:9000", "http://localhost:9001"),
+            ("http://localhost:9000/", "http://localhost:9001"),
+            ("localhost:9000", "http://localhost:9001"),
+            ("http://10.0.0.5:9000/api", "http://10.0.0.5:9001/api"),
+            // Another port is not the backend's own, so it is kept.
+            ("http://localhost:3000", "http://localhost:3000"),
+            // No port: nginx in front routes the admin app under /admin.
+            ("https://example.com", "https://example.com/admin"),
+            ("https://example.com/", "https://example.com/admin"),
+        ];
+        let o = opts("", "admin-url");
+        for (url, expected) in cases {
+            let o = ProbeOptions {
+                url: url.to_string(),
+                ..o.clone()
+            };
+            assert_eq!(o.admin_url(), expected, "{url}");
+        }
+
+        let explicit = ProbeOptions {
+            admin_url: Some("http://admin.internal:7000/".to_string()),
+            ..o.clone()
+        };
+        assert_eq!(explicit.admin_url(), "http://admin.internal:7000");
+        fs::remove_dir_all(&o.root).unwrap();
+    }
+
+    #[test]
+    fn backoff_doubles_between_attempts() {
+        let ms = Duration::from_millis;
+        assert_eq!(backoff(ms(250), 5), [ms(250), ms(500), ms(1000), ms(2000)]);
+        assert!(backoff(ms(250), 1).is_empty());
+        // Zero attempts still makes one.
+        assert!(backoff(ms(250), 0).is_empty());
+        assert_eq!(backoff(Duration::MAX, 3), [Duration::MAX, Duration::MAX]);
+    }
+}
@@ -0,0 +1,220 @@
+use clap::ValueEnum;
+use std::collections::BTreeMap;
+
+use crate::checks::CheckTarget;
+use crate::config::{Config, ProfileConfig};
+use crate::error::CliError;
+use crate::report::{Finding, Status};
+use crate::util::glob::glob_match;
+
+/// A built-in profile with the overrides from `monorepo-cli.toml` applied.
+#[derive(Debug, Clone)]
+pub struct Profile {
+    pub name: String,
+    /// Env file validated under apps/be, e.g. `.env` or `.env.production`.
+    pub env_file: String,
+    /// Days before expiry at which a TLS certificate is reported.
+    pub cert_warn_days: u32,
+    /// Extra targets added to `check all`.
+    pub checks: Vec<CheckTarget>,
+    /// Finding id (or glob such as `build.*`) -> status used when the check is not ok.
+    pub severity: BTreeMap<String, Status>,
+    /// Whether the backend runs in development and so serves `/openapi/json`; inherited through `extends`.
+    pub serves_openapi: bool,
+}
+
+impl Profile {
+    pub fn dev() -> Profile {
+        Profile {
+            name: "dev".to_string(),
+            env_file: ".env".to_string(),
+            cert_warn_days: 30,
+            checks: Vec::new(),
+            severity: BTreeMap::new(),
+            serves_openapi: true,
+        }
+    }
+
+    pub fn prod() -> Profile {
+        let severity = [
+            "nginx.*",
+            "pm2.*",
+            "build.*",
+            "env.be",
+            "env.required_keys",
+            "db.sqlite",
+            "git.*",
+            "fe.robots",
+            "fe.dist_assets",
+            "fe.vite_out_dir",
+        ]
+        .iter()
+        .map(|id| (id.to_string(), Status::Fail))
+        .collect();
+
+        Profile {
+            name: "prod".to_string(),
+            env_file: ".env.production".to_string(),
+            cert_warn_days: 30,
+            checks: vec![
+                CheckTarget::Security,
+                CheckTarget::Cors,
+                CheckTarget::Db,
+                CheckTarget::Git,
+            ],
+            severity,
+            serves_openapi: false,
+        }
+    }
+
+    pub fn resolve(name: &str, config: &Config) -> Result<Profile, CliError> {
+        resolve_inner(name, config, 0)
+    }
+
+    fn apply(mut self, name: &str, cfg: &ProfileConfig) -> Result<Profile, CliError> {
+        self.name = name.to_string();
+        if let Some(env_file) = &cfg.env_file {
+            self.env_file = env_file.clone();
+        }
+        if let Some(days) = cfg.cert_warn_days {
+            self.cert_warn_days = days;
+        }
+        for c in &cfg.checks {
+            let target = CheckTarget::from_str(c, true).map_err(|_| {
+                CliError::Config(format!("profile {name}: unknown check target `{c}`"))
+            })?;
+            if !self.checks.iter().any(|t| t == &target) {
+                self.checks.push(target);
+            }
+        }
+        self.severity
+            .extend(cfg.severity.iter().map(|(k, v)| (k.clone(), *v)));
+        Ok(self)
+    }
+
+    /// The most specific pattern applies: an exact id over any glob, then the longest matching glob
+    /// (`build.fe.*` over `build.*` over `*`). Equally long globs fall back to sorted order.
+    pub fn severity_for(&self, id: &str) -> Option<Status> {
+        if let Some(s) = self.severity.get(id) {
+            return Some(*s);
+        }
+        self.severity
+            .iter()
+            .filter(|(pattern, _)| glob_match(pattern, id))
+            .fold(
+                None,
+                |best: Option<(&String, &Status)>, candidate| match best {
+                    Some(b) if b.0.len() >= candidate.0.len() => Some(b),
+                    _ => Some(candidate),
+                },
+            )
+            .map(|(_, s)| *s)
+    }
+
+    pub fn adjust(&self, f: &mut Finding) {
+        if f.status == Status::Ok {
+            return;
+        }
+        if let Some(status) = self.severity_for(&f.id)
+            && status != f.status
+        {
+            f.meta.insert(
+                "profile_severity".to_string(),
+                format!("{:?} -> {:?} ({})", f.status, status, self.name).to_lowercase(),
+            );
+            f.status = status;
+        }
+    }
+}
+
+fn resolve_inner(name: &str, config: &Config, depth: usize) -> Result<Profile, CliError> {
+    if depth > 8 {
+        return Err(CliError::Config(format!(
+            "profile {name}: `extends` chain is too deep (cycle?)"
+        )));
+    }
+
+    match config.profiles.get(name) {
+        Some(cfg) => {
+            let base = match cfg.extends.as_deref() {
+                Some(parent) if parent == name => builtin(parent).ok_or_else(|| {
+                    CliError::Config(format!("profile {name} cannot extend itself"))
+                })?,
+                Some(parent) => resolve_inner(parent, config, depth + 1)?,
+                None => builtin(name).unwrap_or_else(Profile::dev),
+            };
+            base.apply(name, cfg)
+        }
+        None => builtin(name).ok_or_else(|| {
+            let mut known: Vec<&str> = vec!["dev", "prod"];
+            known.extend(config.profiles.keys().map(|k| k.as_str()));
+            CliError::Config(format!(
+                "unknown profile `{name}` (known: {})",
+                known.join(", ")
+            ))
+        }),
+    }
+}
+
+fn builtin(name: &str) -> Option<Profile> {
+    match name {
+        "dev" | "development" => Some(Profile::dev()),
+        "prod" | "production" => Some(Profile::prod()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn most_specific_severity_wins() {
+        let mut p = Profile::dev();
+        for (pattern, status) in [
+            ("*", Status::Ok),
+            ("build.*", Status::Fail),
+            ("build.fe.*", Status::Warn),
+            ("build.fe.size", Status::Fail),
+            ("nginx.*", Status::Fail),
+            ("*.check", Status::Warn),
+        ] {
+            p.severity.insert(pattern.to_string(), status);
+        }
+        assert_eq!(p.severity_for("build.fe.size"), Some(Status::Fail));
+        assert_eq!(p.severity_for("build.fe.dist"), Some(Status::Warn));
+        assert_eq!(p.severity_for("build.be"), Some(Status::Fail));
+        assert_eq!(p.severity_for("docs.links"), Some(Status::Ok));
+        // Both globs are seven characters long; sorted order picks `*.check`.
+        assert_eq!(p.severity_for("nginx.check"), Some(Status::Warn));
+        p.severity.clear();
+        assert_eq!(p.severity_for("docs.links"), None);
+    }
+
+    fn config(text: &str) -> Config {
+        toml::from_str(text).unwrap()
+    }
+
+    #[test]
+    fn openapi_follows_the_extended_profile() {
+        let c = config(
+            r#"
+[profiles.local]
+extends = "dev"
+env_file = ".env.local"
+
+[profiles.staging]
+extends = "prod"
+
+[profiles.qa]
+extends = "staging"
+"#,
+        );
+        let serves = |name: &str| Profile::resolve(name, &c).unwrap().serves_openapi;
+        assert!(serves("dev"));
+        assert!(serves("local"));
+        assert!(!serves("prod"));
+        assert!(!serves("staging"));
+        assert!(!serves("qa"));
+    }
+}
@@ -0,0 +1,192 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Write};
+
+use crate::report::{Finding, Report, Status};
+use crate::util::time::parse_rfc3339;
+
+const PREFIX: &str = "monorepo_readiness";
+
+/// Renders a report in the Prometheus text exposition format, for node_exporter's textfile
+/// collector. `--out` writes it to a temporary file renamed into place, so a scrape never sees a
+/// half-written file.
+pub fn render(report: &Report) -> String {
+    let mut out = String::new();
+    write_metrics(&mut out, report).expect("writing to a String cannot fail");
+    out
+}
+
+fn write_metrics(out: &mut String, report: &Report) -> fmt::Result {
+    let profile = escape(&report.profile);
+
+    header(
+        out,
+        "finding",
+        "Status of a readiness finding: 0 ok, 1 warn, 2 fail.",
+    )?;
+    for f in worst_per_id(&report.findings) {
+        writeln!(
+            out,
+            "{PREFIX}_finding{{id=\"{}\",status=\"{}\",category=\"{}\",profile=\"{profile}\"}} {}",
+            escape(&f.id),
+            status_key(f.status),
+            escape(&f.category),
+            status_value(f.status)
+        )?;
+    }
+
+    header(out, "findings", "Number of reported findings by status.")?;
+    for status in [Status::Ok, Status::Warn, Status::Fail] {
+        let key = status_key(status);
+        writeln!(
+            out,
+            "{PREFIX}_findings{{status=\"{key}\",profile=\"{profile}\"}} {}",
+            report.counts.get(key).copied().unwrap_or(0)
+        )?;
+    }
+
+    // The textfile collector rejects sample timestamps, so the run time is a gauge of its own.
+    if let Some(started) = parse_rfc3339(&report.run.started_at) {
+        header(
+            out,
+            "run_timestamp_seconds",
+            "Unix time the readiness checks started.",
+        )?;
+        writeln!(
+            out,
+            "{PREFIX}_run_timestamp_seconds{{profile=\"{profile}\"}} {started}"
+        )?;
+    }
+    header(
+        out,
+        "run_duration_seconds",
+        "Time spent running the readiness checks.",
+    )?;
+    writeln!(
+        out,
+        "{PREFIX}_run_duration_seconds{{profile=\"{profile}\"}} {}",
+        report.run.duration_ms as f64 / 1000.0
+    )
+}
+
+fn header(out: &mut String, name: &str, help: &str) -> fmt::Result {
+    writeln!(out, "# HELP {PREFIX}_{name} {help}")?;
+    writeln!(out, "# TYPE {PREFIX}_{name} gauge")
+}
+
+// Prometheus rejects two samples with the same labels, so an id reported more than once
+// (e.g. by two custom checks) keeps its worst finding.
+fn worst_per_id(findings: &[Finding]) -> Vec<&Finding> {
+    let mut worst: BTreeMap<&str, &Finding> = BTreeMap::new();
+    for f in findings {
+        worst
+            .entry(&f.id)
+            .and_modify(|w| {
+                if f.status > w.status {
+                    *w = f;
+                }
+            })
+            .or_insert(f);
+    }
+    worst.into_values().collect()
+}
+
+fn status_value(status: Status) -> u8 {
+    match status {
+        Status::Ok => 0,
+        Status::Warn => 1,
+        Status::Fail => 2,
+    }
+}
+
+fn status_key(status: Status) -> &'static str {
+    match status {
+        Status::Ok => "ok",
+        Status::Warn => "warn",
+        Status::Fail => "fail",
+    }
+}
+
+// Label values escape backslash, double quote and newline.
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::{Run, finding};
+
+    fn report(findings: Vec<Finding>) -> Report {
+        Report {
+            schema_version: 1,
+            root: "/srv/app".to_string(),
+            profile: "prod".to_string(),
+            run: Run {
+                started_at: "2026-01-02T03:04:05Z".to_string(),
+                duration_ms: 1500,
+                ..Default::default()
+            },
+            counts: [("ok", 1), ("warn", 0), ("fail", 2)]
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v))
+                .collect(),
+            findings,
+        }
+    }
+
+    #[test]
+    fn label_values_are_escaped() {
+        let mut r = report(vec![
+            finding("custom.a\"b", "t", Status::Fail, "").category("dir\\sub\nnext"),
+        ]);
+        r.profile = "q\"a".to_string();
+        let text = render(&r);
+        assert!(text.contains(
+            "monorepo_readiness_finding{id=\"custom.a\\\"b\",status=\"fail\",category=\"dir\\\\sub\\nnext\",profile=\"q\\\"a\"} 2\n"
+        ));
+        assert!(!text.contains("sub\nnext"));
+    }
+
+    #[test]
+    fn duplicate_ids_keep_the_worst_status() {
+        let text = render(&report(vec![
+            finding("custom.disk", "t", Status::Warn, ""),
+            finding("custom.disk", "t", Status::Fail, ""),
+            finding("custom.disk", "t", Status::Ok, ""),
+            finding("env.be", "t", Status::Ok, ""),
+        ]));
+        let samples: Vec<&str> = text
+            .lines()
+            .filter(|l| l.starts_with("monorepo_readiness_finding{"))
+            .collect();
+        assert_eq!(
+            samples,
+            [
+                "monorepo_readiness_finding{id=\"custom.disk\",status=\"fail\",category=\"custom\",profile=\"prod\"} 2",
+                "monorepo_readiness_finding{id=\"env.be\",status=\"ok\",category=\"env\",profile=\"prod\"} 0",
+            ]
+        );
+    }
+
+    #[test]
+    fn counts_and_run_gauges() {
+        let text = render(&report(Vec::new()));
+        for line in [
+            "monorepo_readiness_findings{status=\"ok\",profile=\"prod\"} 1",
+            "monorepo_readiness_findings{status=\"warn\",profile=\"prod\"} 0",
+            "monorepo_readiness_findings{status=\"fail\",profile=\"prod\"} 2",
+            "monorepo_readiness_run_timestamp_seconds{profile=\"prod\"} 1767323045",
+            "monorepo_readiness_run_duration_seconds{profile=\"prod\"} 1.5",
+        ] {
+            assert!(text.lines().any(|l| l == line), "missing {line} in\n{text}");
+        }
+        assert_eq!(text.matches("# TYPE ").count(), 4);
+
+        let mut r = report(Vec::new());
+        r.run.started_at = "not a time".to_string();
+        assert!(!render(&r).contains("run_timestamp_seconds"));
+    }
+}
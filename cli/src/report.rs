@@ -1,7 +1,16 @@
-use serde::Serialize;
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
+use std::fmt::{self, Write};
+use std::io;
+use std::path::Path;
 
-#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+use crate::error::exit;
+use crate::i18n::Lang;
+use crate::util::file::FileError;
+use crate::util::glob::glob_match;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
 #[serde(rename_all = "lowercase")]
 pub enum Status {
     Ok,
@@ -9,66 +18,294 @@ pub enum Status {
     Fail,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Location {
+    pub path: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub column: Option<usize>,
+}
+
+impl std::fmt::Display for Location {
+    // `path:line:column`, which most terminals and editors turn into a link.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.path)?;
+        if let Some(line) = self.line {
+            write!(f, ":{line}")?;
+            if let Some(column) = self.column {
+                write!(f, ":{column}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Finding {
     pub id: String,
     pub title: String,
     pub status: Status,
     pub details: String,
+    #[serde(default)]
+    pub category: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<Location>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remediation: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub docs: Option<String>,
+    #[serde(default)]
     pub meta: BTreeMap<String, String>,
 }
 
-#[derive(Debug, Serialize)]
+impl Finding {
+    pub fn at(mut self, path: impl AsRef<Path>, line: Option<usize>) -> Finding {
+        self.location = Some(Location {
+            path: path.as_ref().to_string_lossy().to_string(),
+            line,
+            column: None,
+        });
+        self
+    }
+
+    pub fn at_column(mut self, column: usize) -> Finding {
+        if let Some(loc) = &mut self.location {
+            loc.column = Some(column);
+        }
+        self
+    }
+
+    pub fn fix(mut self, remediation: impl Into<String>) -> Finding {
+        self.remediation = Some(remediation.into());
+        self
+    }
+
+    pub fn docs(mut self, docs: impl Into<String>) -> Finding {
+        self.docs = Some(docs.into());
+        self
+    }
+
+    pub fn category(mut self, category: impl Into<String>) -> Finding {
+        self.category = category.into();
+        self
+    }
+
+    pub fn tags(mut self, tags: &[&str]) -> Finding {
+        self.tags.extend(tags.iter().map(|t| t.to_string()));
+        self
+    }
+
+    pub fn meta(mut self, key: &str, value: impl Into<String>) -> Finding {
+        self.meta.insert(key.to_string(), value.into());
+        self
+    }
+}
+
+/// Bumped whenever the JSON layout changes incompatibly; reports written before it existed read as 0.
+pub const SCHEMA_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Report {
+    #[serde(default)]
+    pub schema_version: u32,
     pub root: String,
+    #[serde(default)]
+    pub profile: String,
+    #[serde(default)]
+    pub run: Run,
     pub counts: BTreeMap<String, usize>,
     pub findings: Vec<Finding>,
 }
 
+/// When, where and with what a report was produced.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Run {
+    pub cli_version: String,
+    /// RFC 3339, UTC.
+    pub started_at: String,
+    pub duration_ms: u64,
+    #[serde(default)]
+    pub hostname: Option<String>,
+    pub os: String,
+    pub arch: String,
+    /// Version of each probed tool, `None` when it is not installed.
+    #[serde(default)]
+    pub tools: BTreeMap<String, Option<String>>,
+    #[serde(default)]
+    pub git: Option<GitInfo>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitInfo {
+    pub commit: String,
+    /// `None` for a detached HEAD.
+    #[serde(default)]
+    pub branch: Option<String>,
+    /// Uncommitted changes to tracked files; `None` when git is not installed.
+    #[serde(default)]
+    pub dirty: Option<bool>,
+}
+
 pub fn finding(id: &str, title: &str, status: Status, details: impl Into<String>) -> Finding {
     Finding {
         id: id.to_string(),
         title: title.to_string(),
         status,
         details: details.into(),
+        category: id.split('.').next().unwrap_or_default().to_string(),
+        tags: Vec::new(),
+        location: None,
+        remediation: None,
+        docs: None,
         meta: BTreeMap::new(),
     }
 }
 
-pub fn print_human_report(report: &Report) {
-    println!("Root: {}", report.root);
-    println!(
-        "Counts: ok={} warn={} fail={}",
+/// A failing finding for an input that exists but cannot be read.
+pub fn unreadable(id: &str, title: &str, path: &Path, err: &io::Error) -> Finding {
+    finding(
+        id,
+        title,
+        Status::Fail,
+        format!("Cannot read {}: {err}", path.display()),
+    )
+    .at(path, None)
+    .fix("Check the file's permissions and that it is UTF-8 text")
+    .meta("error", err.kind().to_string())
+}
+
+/// A failing finding for an input that was read but is not valid for its format.
+pub fn invalid(
+    id: &str,
+    title: &str,
+    path: &Path,
+    line: Option<usize>,
+    err: impl fmt::Display,
+) -> Finding {
+    finding(
+        id,
+        title,
+        Status::Fail,
+        format!("Cannot parse {}: {err}", path.display()),
+    )
+    .at(path, line)
+    .fix("Fix the syntax error or restore the file from version control")
+    .meta("error", "invalid data")
+}
+
+/// [`unreadable`] or [`invalid`], whichever `err` is.
+pub fn file_error(id: &str, title: &str, path: &Path, err: &FileError) -> Finding {
+    match err {
+        FileError::Read(e) => unreadable(id, title, path, e),
+        FileError::Parse { line, message } => invalid(id, title, path, *line, message),
+    }
+}
+
+/// Number of findings per status; every status is present, even at zero.
+pub fn counts(findings: &[Finding]) -> BTreeMap<String, usize> {
+    let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+    for s in [Status::Ok, Status::Warn, Status::Fail] {
+        counts.insert(format!("{s:?}").to_lowercase(), 0);
+    }
+    for f in findings {
+        let k = format!("{:?}", f.status).to_lowercase();
+        *counts.entry(k).or_insert(0) += 1;
+    }
+    counts
+}
+
+pub fn print_human_report(report: &Report, lang: Lang) {
+    print!("{}", human_report(report, lang));
+}
+
+pub fn human_report(report: &Report, lang: Lang) -> String {
+    let mut out = String::new();
+    write_human(&mut out, report, lang).expect("writing to a String cannot fail");
+    out
+}
+
+fn write_human(out: &mut String, report: &Report, lang: Lang) -> fmt::Result {
+    writeln!(out, "{}: {}", lang.tr("Root"), report.root)?;
+    writeln!(out, "{}: {}", lang.tr("Profile"), report.profile)?;
+    let run = &report.run;
+    let mut line = format!(
+        "{}: cli {} | {} | {} ms",
+        lang.tr("Run"),
+        run.cli_version,
+        run.started_at,
+        run.duration_ms
+    );
+    if let Some(git) = &run.git {
+        let short = git.commit.get(..7).unwrap_or(&git.commit);
+        line.push_str(&format!(
+            " | {}@{short}",
+            git.branch.as_deref().unwrap_or("HEAD")
+        ));
+        if git.dirty == Some(true) {
+            line.push_str(" (dirty)");
+        }
+    }
+    writeln!(out, "{line}")?;
+    writeln!(
+        out,
+        "{}: ok={} warn={} fail={}",
+        lang.tr("Counts"),
         report.counts.get("ok").copied().unwrap_or(0),
         report.counts.get("warn").copied().unwrap_or(0),
         report.counts.get("fail").copied().unwrap_or(0)
-    );
-    println!();
+    )?;
+    writeln!(out)?;
 
     for f in &report.findings {
-        println!("[{:?}] {} ({})", f.status, f.title, f.id);
-        println!("  {}", f.details);
+        writeln!(out, "[{:?}] {} ({})", f.status, f.title, f.id)?;
+        if let Some(loc) = &f.location {
+            writeln!(out, "  {loc}")?;
+        }
+        writeln!(out, "  {}", f.details)?;
+        if let Some(fix) = &f.remediation {
+            writeln!(out, "  {}: {fix}", lang.tr("fix"))?;
+        }
+        if let Some(docs) = &f.docs {
+            writeln!(out, "  {}: {docs}", lang.tr("docs"))?;
+        }
+        write!(out, "  {}: {}", lang.tr("category"), f.category)?;
+        if !f.tags.is_empty() {
+            write!(out, " | {}: {}", lang.tr("tags"), f.tags.join(", "))?;
+        }
+        writeln!(out)?;
         if !f.meta.is_empty() {
             for (k, v) in &f.meta {
-                println!("  - {}: {}", k, v);
+                writeln!(out, "  - {}: {}", k, v)?;
             }
         }
-        println!();
+        writeln!(out)?;
     }
+    Ok(())
 }
 
-pub fn exit_code(report: &Report, strict: bool) -> i32 {
-    let has_fail = report.findings.iter().any(|f| f.status == Status::Fail);
-    let has_warn = report.findings.iter().any(|f| f.status == Status::Warn);
+/// [`exit::FAIL`] on failures (or warnings when `strict`), [`exit::WARN`] on warnings, else
+/// [`exit::OK`]. A non-empty `fail_on` limits which finding ids (globs such as `nginx.*`) can affect the code.
+pub fn exit_code(report: &Report, strict: bool, fail_on: &[String]) -> i32 {
+    let gating = |f: &&Finding| fail_on.is_empty() || fail_on.iter().any(|p| glob_match(p, &f.id));
+    let has_fail = report
+        .findings
+        .iter()
+        .filter(gating)
+        .any(|f| f.status == Status::Fail);
+    let has_warn = report
+        .findings
+        .iter()
+        .filter(gating)
+        .any(|f| f.status == Status::Warn);
 
-    if has_fail {
-        return 2;
-    }
-    if strict && has_warn {
-        return 2;
+    if has_fail || (strict && has_warn) {
+        return exit::FAIL;
     }
     if has_warn {
-        return 1;
+        return exit::WARN;
     }
-    0
+    exit::OK
 }
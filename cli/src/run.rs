@@ -0,0 +1,55 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+use crate::report::{GitInfo, Run};
+use crate::util::command::command_version;
+use crate::util::git;
+use crate::util::time::rfc3339;
+
+// Tools the checks care about, with the arguments that print their version.
+const TOOLS: [(&str, &[&str]); 4] = [
+    ("bun", &["--version"]),
+    ("node", &["--version"]),
+    ("nginx", &["-v"]),
+    ("pm2", &["--version"]),
+];
+
+/// Describes the current run; `elapsed` is the time spent running checks.
+pub fn collect(root: &Path, started: SystemTime, elapsed: Duration) -> Run {
+    let tools = TOOLS
+        .iter()
+        .map(|(name, args)| (name.to_string(), command_version(name, args)))
+        .collect::<BTreeMap<_, _>>();
+
+    let git = git::head(root).map(|head| GitInfo {
+        commit: head.commit,
+        branch: head.branch,
+        dirty: git::is_dirty(root),
+    });
+
+    Run {
+        cli_version: env!("CARGO_PKG_VERSION").to_string(),
+        started_at: rfc3339(started),
+        duration_ms: elapsed.as_millis() as u64,
+        hostname: hostname(),
+        os: std::env::consts::OS.to_string(),
+        arch: std::env::consts::ARCH.to_string(),
+        tools,
+        git,
+    }
+}
+
+fn hostname() -> Option<String> {
+    let from_env = ["HOSTNAME", "COMPUTERNAME"]
+        .iter()
+        .filter_map(|k| std::env::var(k).ok());
+    let from_files = ["/proc/sys/kernel/hostname", "/etc/hostname"]
+        .iter()
+        .filter_map(|p| fs::read_to_string(p).ok());
+    from_env
+        .chain(from_files)
+        .map(|h| h.trim().to_string())
+        .find(|h| !h.is_empty())
+}
@@ -0,0 +1,143 @@
+use crate::error::CliError;
+use crate::util::repo::BACKEND_PROCESS_TYPES;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Where the units are written, relative to the repository root.
+pub const SYSTEMD_DIR: &str = "deploy/systemd";
+
+/// Settings baked into the generated systemd units.
+#[derive(Debug, Clone)]
+pub struct SystemdOptions {
+    /// Absolute path of the checkout on the server.
+    pub install_dir: String,
+    pub user: String,
+    /// Absolute path of the bun executable.
+    pub bun: String,
+    pub port: u16,
+    pub admin_port: u16,
+}
+
+impl Default for SystemdOptions {
+    fn default() -> SystemdOptions {
+        SystemdOptions {
+            install_dir: "/srv/app".to_string(),
+            user: "app".to_string(),
+            bun: "/usr/local/bin/bun".to_string(),
+            port: 8000,
+            admin_port: 8001,
+        }
+    }
+}
+
+/// One unit per backend process type, as `(file name, contents)`.
+pub fn systemd_units(opts: &SystemdOptions) -> Vec<(String, String)> {
+    BACKEND_PROCESS_TYPES
+        .iter()
+        .map(|ty| (format!("be-{ty}.service"), systemd_unit(ty, opts)))
+        .collect()
+}
+
+fn systemd_unit(process_type: &str, opts: &SystemdOptions) -> String {
+    let dir = format!("{}/apps/be", opts.install_dir.trim_end_matches('/'));
+    let port = match process_type {
+        "main" => format!("Environment=PORT={}\n", opts.port),
+        "admin" => format!("Environment=ADMIN_PORT={}\n", opts.admin_port),
+        _ => String::new(),
+    };
+    format!(
+        "[Unit]
+Description=Backend ({process_type})
+After=network-online.target
+Wants=network-online.target
+
+[Service]
+Type=simple
+User={user}
+WorkingDirectory={dir}
+EnvironmentFile=-{dir}/.env.production
+Environment=NODE_ENV=production
+Environment=PROCESS_TYPE={process_type}
+{port}ExecStart={bun} dist/index.js
+Restart=on-failure
+RestartSec=5
+
+[Install]
+WantedBy=multi-user.target
+",
+        user = opts.user,
+        bun = opts.bun,
+    )
+}
+
+/// Writes the units under `<root>/deploy/systemd`, refusing to replace existing files unless `force`.
+pub fn write_systemd_units(
+    root: &Path,
+    opts: &SystemdOptions,
+    force: bool,
+) -> Result<Vec<PathBuf>, CliError> {
+    let dir = root.join(SYSTEMD_DIR);
+    let units = systemd_units(opts);
+    if !force {
+        let existing: Vec<String> = units
+            .iter()
+            .map(|(name, _)| dir.join(name))
+            .filter(|p| p.exists())
+            .map(|p| p.display().to_string())
+            .collect();
+        if !existing.is_empty() {
+            return Err(CliError::Scaffold(format!(
+                "{} already exist(s); pass --force to overwrite",
+                existing.join(", ")
+            )));
+        }
+    }
+
+    fs::create_dir_all(&dir)?;
+    let mut written = Vec::new();
+    for (name, contents) in units {
+        let path = dir.join(name);
+        fs::write(&path, contents)?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checks::systemd;
+    use crate::report::Status;
+
+    fn scratch(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("scaffold-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("apps/be")).unwrap();
+        dir
+    }
+
+    #[test]
+    fn generated_units_pass_the_systemd_check() {
+        let root = scratch("systemd");
+        let env_file = root.join("apps/be/.env.production");
+        fs::write(&env_file, "PORT=9000\nADMIN_PORT=9001\n").unwrap();
+        let opts = SystemdOptions {
+            port: 9000,
+            admin_port: 9001,
+            ..SystemdOptions::default()
+        };
+        let written = write_systemd_units(&root, &opts, false).unwrap();
+        assert_eq!(written.len(), BACKEND_PROCESS_TYPES.len());
+
+        let found = systemd::check(&root, &env_file);
+        assert_eq!(found.len(), 6);
+        for f in &found {
+            assert_eq!(f.status, Status::Ok, "{}: {}", f.id, f.details);
+        }
+
+        // A second run keeps the units unless forced.
+        assert!(write_systemd_units(&root, &opts, false).is_err());
+        assert!(write_systemd_units(&root, &opts, true).is_ok());
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
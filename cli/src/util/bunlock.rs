@@ -0,0 +1,273 @@
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+pub const DEP_FIELDS: [&str; 4] = [
+    "dependencies",
+    "devDependencies",
+    "optionalDependencies",
+    "peerDependencies",
+];
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BunLock {
+    #[serde(default)]
+    pub lockfile_version: u32,
+    #[serde(default)]
+    pub workspaces: BTreeMap<String, LockWorkspace>,
+    #[serde(default)]
+    pub packages: BTreeMap<String, Value>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LockWorkspace {
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub dependencies: BTreeMap<String, String>,
+    #[serde(default)]
+    pub dev_dependencies: BTreeMap<String, String>,
+    #[serde(default)]
+    pub optional_dependencies: BTreeMap<String, String>,
+    #[serde(default)]
+    pub peer_dependencies: BTreeMap<String, String>,
+}
+
+impl LockWorkspace {
+    pub fn all_dependencies(&self) -> BTreeMap<String, String> {
+        let mut out = BTreeMap::new();
+        for deps in [
+            &self.peer_dependencies,
+            &self.optional_dependencies,
+            &self.dev_dependencies,
+            &self.dependencies,
+        ] {
+            out.extend(deps.iter().map(|(k, v)| (k.clone(), v.clone())));
+        }
+        out
+    }
+}
+
+pub struct LockPackage {
+    pub name: String,
+    pub version: String,
+    pub dependencies: BTreeMap<String, String>,
+}
+
+impl BunLock {
+    pub fn parse(input: &str) -> Result<BunLock, serde_json::Error> {
+        serde_json::from_str(&strip_jsonc(input))
+    }
+
+    pub fn package(&self, key: &str) -> Option<LockPackage> {
+        let entry = self.packages.get(key)?.as_array()?;
+        let ident = entry.first()?.as_str()?;
+        let (name, version) = split_ident(ident)?;
+
+        let mut dependencies = BTreeMap::new();
+        if let Some(info) = entry.iter().find_map(|v| v.as_object()) {
+            for field in DEP_FIELDS {
+                if let Some(deps) = info.get(field).and_then(|v| v.as_object()) {
+                    for (k, v) in deps {
+                        dependencies.insert(k.clone(), v.as_str().unwrap_or("*").to_string());
+                    }
+                }
+            }
+        }
+
+        Some(LockPackage {
+            name: name.to_string(),
+            version: version.to_string(),
+            dependencies,
+        })
+    }
+
+    // Resolves `dep` as seen from the package stored at `from` (empty for the root),
+    // walking outwards through bun's nested `parent/child` keys like node resolution.
+    pub fn resolve(&self, from: &str, dep: &str) -> Option<String> {
+        let mut scope = split_key(from);
+        loop {
+            let key = if scope.is_empty() {
+                dep.to_string()
+            } else {
+                format!("{}/{}", scope.join("/"), dep)
+            };
+            if self.packages.contains_key(&key) {
+                return Some(key);
+            }
+            scope.pop()?;
+        }
+    }
+}
+
+// `@scope/name@1.2.3` -> (`@scope/name`, `1.2.3`)
+pub fn split_ident(ident: &str) -> Option<(&str, &str)> {
+    let at = ident.get(1..)?.find('@')? + 1;
+    Some((&ident[..at], &ident[at + 1..]))
+}
+
+// `@app/fe/@types/node/undici-types` -> [`@app/fe`, `@types/node`, `undici-types`]
+pub fn split_key(key: &str) -> Vec<String> {
+    let mut out = Vec::new();
+    let mut parts = key.split('/').filter(|s| !s.is_empty());
+    while let Some(p) = parts.next() {
+        if p.starts_with('@') {
+            match parts.next() {
+                Some(n) => out.push(format!("{p}/{n}")),
+                None => out.push(p.to_string()),
+            }
+        } else {
+            out.push(p.to_string());
+        }
+    }
+    out
+}
+
+// bun.lock is JSONC: it allows comments and trailing commas.
+pub fn strip_jsonc(input: &str) -> String {
+    let chars: Vec<char> = input.chars().collect();
+    let mut out = String::with_capacity(input.len());
+    let mut i = 0;
+    let mut in_str = false;
+
+    while i < chars.len() {
+        let c = chars[i];
+        if in_str {
+            out.push(c);
+            if c == '\\' && i + 1 < chars.len() {
+                out.push(chars[i + 1]);
+                i += 1;
+            } else if c == '"' {
+                in_str = false;
+            }
+            i += 1;
+            continue;
+        }
+
+        match c {
+            '"' => {
+                in_str = true;
+                out.push(c);
+            }
+            '/' if chars.get(i + 1) == Some(&'/') => {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+                continue;
+            }
+            '/' if chars.get(i + 1) == Some(&'*') => {
+                i += 2;
+                while i + 1 < chars.len() && !(chars[i] == '*' && chars[i + 1] == '/') {
+                    i += 1;
+                }
+                i += 2;
+                continue;
+            }
+            // Comments are already gone from `out`, so a comma before one still counts as trailing.
+            '}' | ']' => {
+                let body = out.trim_end().len();
+                if out[..body].ends_with(',') {
+                    out.remove(body - 1);
+                }
+                out.push(c);
+            }
+            _ => out.push(c),
+        }
+        i += 1;
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LOCK: &str = r#"{
+  // generated by bun
+  "lockfileVersion": 1,
+  "workspaces": {
+    "": { "name": "root", "devDependencies": { "typescript": "^5" }, },
+    "apps/fe": {
+      "name": "@app/fe",
+      "dependencies": { "react": "^18.2.0" },
+      "devDependencies": { "react": "^18.0.0", "@types/node": "^20" },
+    },
+  },
+  "packages": {
+    "react": ["react@18.3.1", "", { "dependencies": { "loose-envify": "^1.1.0" } }, "sha512-x"],
+    "loose-envify": ["loose-envify@1.4.0", "", {}, "sha512-y"],
+    "@types/node": ["@types/node@20.11.0", "", { "dependencies": { "undici-types": "~5.26.4" } }, "sha512-z"],
+    "@types/node/undici-types": ["undici-types@5.26.5", "", {}, "sha512-w"],
+    "undici-types": ["undici-types@6.0.0", "", {}, "sha512-v"], /* hoisted */
+  },
+}"#;
+
+    #[test]
+    fn strips_comments_and_trailing_commas_outside_strings() {
+        assert_eq!(
+            strip_jsonc(r#"{"a": "//x, /*y*/",}"#),
+            r#"{"a": "//x, /*y*/"}"#
+        );
+        assert_eq!(strip_jsonc("[1, 2, // two\n ]"), "[1, 2 \n ]");
+        assert_eq!(strip_jsonc("{\"a\": [1,] /* c */,\n}"), "{\"a\": [1] \n}");
+        assert_eq!(strip_jsonc(r#"{"a\"//": 1 /* c */}"#), r#"{"a\"//": 1 }"#);
+    }
+
+    #[test]
+    fn parses_workspaces_and_packages() {
+        let lock = BunLock::parse(LOCK).unwrap();
+        assert_eq!(lock.lockfile_version, 1);
+        let fe = &lock.workspaces["apps/fe"];
+        assert_eq!(fe.name, "@app/fe");
+        // A runtime dependency wins over a dev entry for the same package.
+        assert_eq!(fe.all_dependencies()["react"], "^18.2.0");
+        assert_eq!(fe.all_dependencies().len(), 2);
+
+        let node = lock.package("@types/node").unwrap();
+        assert_eq!(
+            (node.name.as_str(), node.version.as_str()),
+            ("@types/node", "20.11.0")
+        );
+        assert_eq!(node.dependencies["undici-types"], "~5.26.4");
+        assert!(lock.package("missing").is_none());
+    }
+
+    #[test]
+    fn resolves_nested_keys_before_hoisted_ones() {
+        let lock = BunLock::parse(LOCK).unwrap();
+        assert_eq!(
+            lock.resolve("@types/node", "undici-types").as_deref(),
+            Some("@types/node/undici-types")
+        );
+        assert_eq!(
+            lock.resolve("react", "undici-types").as_deref(),
+            Some("undici-types")
+        );
+        assert_eq!(
+            lock.resolve("", "loose-envify").as_deref(),
+            Some("loose-envify")
+        );
+        assert_eq!(lock.resolve("react", "left-pad"), None);
+    }
+
+    #[test]
+    fn splits_scoped_idents_and_keys() {
+        assert_eq!(
+            split_ident("@scope/name@1.2.3"),
+            Some(("@scope/name", "1.2.3"))
+        );
+        assert_eq!(
+            split_ident("name@npm:other@2"),
+            Some(("name", "npm:other@2"))
+        );
+        assert_eq!(split_ident("name"), None);
+        assert_eq!(
+            split_key("@app/fe/@types/node/undici-types"),
+            ["@app/fe", "@types/node", "undici-types"]
+        );
+        assert!(split_key("").is_empty());
+    }
+}
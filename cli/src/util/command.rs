@@ -1,3 +1,6 @@
+use regex::Regex;
+use std::process::Command;
+
 pub fn command_exists(cmd: &str) -> bool {
     let path = std::env::var_os("PATH").unwrap_or_default();
     for dir in std::env::split_paths(&path) {
@@ -15,3 +18,18 @@ pub fn command_exists(cmd: &str) -> bool {
     }
     false
 }
+
+// First version-like token (`1.3.5`, `v22.11.0`) printed by `cmd args`, on stdout or stderr.
+pub fn command_version(cmd: &str, args: &[&str]) -> Option<String> {
+    if !command_exists(cmd) {
+        return None;
+    }
+    let output = Command::new(cmd).args(args).output().ok()?;
+    let text = format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let re = Regex::new(r"\d+\.\d+(?:\.\d+)?").expect("valid version pattern");
+    re.find(&text).map(|m| m.as_str().to_string())
+}
@@ -0,0 +1,178 @@
+use std::collections::BTreeMap;
+
+use super::glob::glob_match;
+
+// One Dockerfile instruction; `line` is where it starts (1-based).
+#[derive(Debug, Clone)]
+pub struct Instruction {
+    // Upper-cased, e.g. `FROM`.
+    pub keyword: String,
+    pub args: String,
+    pub line: usize,
+}
+
+// Splits a Dockerfile into instructions, joining `\` continuations and skipping comments.
+pub fn parse(text: &str) -> Vec<Instruction> {
+    let mut out = Vec::new();
+    let mut current: Option<(String, usize)> = None;
+
+    for (i, raw) in text.lines().enumerate() {
+        let line = raw.trim();
+        if line.starts_with('#') || (line.is_empty() && current.is_none()) {
+            continue;
+        }
+        let (body, continued) = match line.strip_suffix('\\') {
+            Some(rest) => (rest.trim_end(), true),
+            None => (line, false),
+        };
+        let (mut text, start) = current.take().unwrap_or((String::new(), i + 1));
+        if !text.is_empty() {
+            text.push(' ');
+        }
+        text.push_str(body);
+        if continued {
+            current = Some((text, start));
+            continue;
+        }
+        if let Some(ins) = instruction(&text, start) {
+            out.push(ins);
+        }
+    }
+    if let Some((text, start)) = current
+        && let Some(ins) = instruction(&text, start)
+    {
+        out.push(ins);
+    }
+    out
+}
+
+fn instruction(text: &str, line: usize) -> Option<Instruction> {
+    let text = text.trim();
+    if text.is_empty() {
+        return None;
+    }
+    let (keyword, args) = text.split_once(char::is_whitespace).unwrap_or((text, ""));
+    Some(Instruction {
+        keyword: keyword.to_uppercase(),
+        args: args.trim().to_string(),
+        line,
+    })
+}
+
+// `ARG` defaults and `ENV` values declared so far, for expanding `$NAME` and `${NAME}`.
+#[derive(Debug, Clone, Default)]
+pub struct Vars {
+    values: BTreeMap<String, String>,
+}
+
+impl Vars {
+    // Records `ARG NAME=value`, `ENV NAME=value` and the legacy `ENV NAME value`.
+    pub fn record(&mut self, ins: &Instruction) {
+        match ins.keyword.as_str() {
+            "ARG" | "ENV" => {}
+            _ => return,
+        }
+        if !ins.args.contains('=') {
+            if let Some((k, v)) = ins.args.split_once(char::is_whitespace) {
+                self.values.insert(k.to_string(), unquote(v.trim()));
+            }
+            return;
+        }
+        for word in ins.args.split_whitespace() {
+            if let Some((k, v)) = word.split_once('=') {
+                let v = self.expand(&unquote(v));
+                self.values.insert(k.to_string(), v);
+            }
+        }
+    }
+
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.values.get(name).map(String::as_str)
+    }
+
+    // Unknown variables are left as written.
+    pub fn expand(&self, text: &str) -> String {
+        let mut out = String::new();
+        let mut rest = text;
+        while let Some(i) = rest.find('$') {
+            out.push_str(&rest[..i]);
+            let after = &rest[i + 1..];
+            let (name, len) = match after.strip_prefix('{') {
+                Some(inner) => match inner.find('}') {
+                    Some(end) => {
+                        // `${NAME:-default}` falls back to the default.
+                        let expr = &inner[..end];
+                        (expr.to_string(), end + 2)
+                    }
+                    None => (String::new(), 0),
+                },
+                None => {
+                    let end = after
+                        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                        .unwrap_or(after.len());
+                    (after[..end].to_string(), end)
+                }
+            };
+            let (var, default) = match name.split_once(":-") {
+                Some((v, d)) => (v, Some(d)),
+                None => (name.as_str(), None),
+            };
+            match self.get(var).or(default) {
+                Some(v) if len > 0 => out.push_str(v),
+                _ => out.push_str(&rest[i..i + 1 + len]),
+            }
+            rest = &rest[i + 1 + len..];
+        }
+        out.push_str(rest);
+        out
+    }
+}
+
+fn unquote(v: &str) -> String {
+    v.trim_matches(|c| c == '"' || c == '\'').to_string()
+}
+
+// `.dockerignore` rules. Unlike `.gitignore`, patterns are relative to the build context root,
+// so `node_modules` only excludes the top-level directory and `**/node_modules` every one.
+#[derive(Debug, Clone, Default)]
+pub struct DockerIgnore {
+    rules: Vec<(String, bool)>,
+}
+
+impl DockerIgnore {
+    pub fn parse(text: &str) -> DockerIgnore {
+        let rules = text
+            .lines()
+            .map(str::trim)
+            .filter(|l| !l.is_empty() && !l.starts_with('#'))
+            .map(|l| match l.strip_prefix('!') {
+                Some(rest) => (clean(rest), false),
+                None => (clean(l), true),
+            })
+            .filter(|(p, _)| !p.is_empty())
+            .collect();
+        DockerIgnore { rules }
+    }
+
+    // The last matching rule wins; a rule that matches a parent directory matches its contents.
+    pub fn excludes(&self, rel: &str) -> bool {
+        let parts: Vec<&str> = rel.split('/').filter(|s| !s.is_empty()).collect();
+        let mut excluded = false;
+        for (pattern, exclude) in &self.rules {
+            let hit = (1..=parts.len()).any(|i| glob_match(pattern, &parts[..i].join("/")));
+            if hit {
+                excluded = *exclude;
+            }
+        }
+        excluded
+    }
+}
+
+fn clean(pattern: &str) -> String {
+    pattern
+        .trim()
+        .trim_start_matches("./")
+        .trim_start_matches('/')
+        .trim_end_matches('/')
+        .to_string()
+}
@@ -1,4 +1,6 @@
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::Path;
 
 pub fn missing_env_keys(example: &str, actual: &str) -> Vec<String> {
     let expected = parse_env_keys(example);
@@ -22,3 +24,56 @@ pub fn parse_env_keys(input: &str) -> BTreeSet<String> {
     }
     keys
 }
+
+pub fn parse_env(input: &str) -> BTreeMap<String, String> {
+    let mut out = BTreeMap::new();
+    for line in input.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let line = line.strip_prefix("export ").unwrap_or(line);
+        if let Some((k, v)) = line.split_once('=') {
+            let k = k.trim();
+            if k.is_empty() {
+                continue;
+            }
+            let v = v.trim();
+            let v = match v.chars().next() {
+                Some(q @ ('"' | '\'')) => v[1..].split(q).next().unwrap_or_default(),
+                _ => v.split(" #").next().unwrap_or_default().trim(),
+            };
+            out.insert(k.to_string(), v.to_string());
+        }
+    }
+    out
+}
+
+pub fn key_line(input: &str, key: &str) -> Option<usize> {
+    input
+        .lines()
+        .position(|line| {
+            let line = line.trim();
+            let line = line.strip_prefix("export ").unwrap_or(line);
+            line.split_once('=').is_some_and(|(k, _)| k.trim() == key)
+        })
+        .map(|i| i + 1)
+}
+
+// `PORT` and `ADMIN_PORT` for the backend: the given env file first, then `.env.example`,
+// then the defaults from apps/be/src/config/process.ts.
+pub fn backend_ports(root: &Path, env_file: &Path) -> (u16, u16) {
+    let example = root.join("apps/be/.env.example");
+    let sources: Vec<BTreeMap<String, String>> = [env_file, example.as_path()]
+        .iter()
+        .filter_map(|p| fs::read_to_string(p).ok())
+        .map(|s| parse_env(&s))
+        .collect();
+    let port = |key: &str, default: u16| {
+        sources
+            .iter()
+            .find_map(|env| env.get(key)?.parse().ok())
+            .unwrap_or(default)
+    };
+    (port("PORT", 8000), port("ADMIN_PORT", 8001))
+}
@@ -0,0 +1,45 @@
+use serde_json::Value;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+// Why an input that exists could not be used.
+#[derive(Debug)]
+pub enum FileError {
+    Read(io::Error),
+    Parse {
+        line: Option<usize>,
+        message: String,
+    },
+}
+
+// Contents of a text file, `None` when it does not exist. Other errors (permissions, invalid
+// UTF-8, a directory in its place) are returned so they are not mistaken for an empty file.
+pub fn read_optional(path: &Path) -> io::Result<Option<String>> {
+    match fs::read_to_string(path) {
+        Ok(s) => Ok(Some(s)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+// Parsed JSON file, `None` when it does not exist.
+pub fn read_json(path: &Path) -> Result<Option<Value>, FileError> {
+    let Some(raw) = read_optional(path).map_err(FileError::Read)? else {
+        return Ok(None);
+    };
+    serde_json::from_str(&raw)
+        .map(Some)
+        .map_err(|e| FileError::Parse {
+            line: Some(e.line()),
+            message: e.to_string(),
+        })
+}
+
+// A TOML syntax error, with the line its span starts on.
+pub fn toml_error(raw: &str, e: &toml::de::Error) -> FileError {
+    FileError::Parse {
+        line: e.span().map(|s| raw[..s.start].matches('\n').count() + 1),
+        message: e.message().to_string(),
+    }
+}
@@ -0,0 +1,242 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+// Files tracked by git, relative to `root`. `None` when git or the repository is unavailable.
+pub fn tracked_files(root: &Path) -> Option<Vec<PathBuf>> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(root)
+        .args(["ls-files", "-z"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    let mut files: Vec<PathBuf> = output
+        .stdout
+        .split(|b| *b == 0)
+        .filter(|s| !s.is_empty())
+        .map(|s| PathBuf::from(String::from_utf8_lossy(s).into_owned()))
+        .collect();
+    files.sort();
+    Some(files)
+}
+
+// HEAD as recorded in `.git`, without running git.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Head {
+    pub commit: String,
+    // `None` for a detached HEAD.
+    pub branch: Option<String>,
+}
+
+pub fn head(root: &Path) -> Option<Head> {
+    let git_dir = git_dir(root)?;
+    let raw = fs::read_to_string(git_dir.join("HEAD")).ok()?;
+    let raw = raw.trim();
+
+    let Some(reference) = raw.strip_prefix("ref:").map(str::trim) else {
+        return Some(Head {
+            commit: raw.to_string(),
+            branch: None,
+        });
+    };
+    let branch = reference
+        .strip_prefix("refs/heads/")
+        .unwrap_or(reference)
+        .to_string();
+    let commit = resolve_ref(&git_dir, reference)?;
+    Some(Head {
+        commit,
+        branch: Some(branch),
+    })
+}
+
+// `.git` is a directory in a normal checkout and a `gitdir: <path>` file in worktrees and submodules.
+fn git_dir(root: &Path) -> Option<PathBuf> {
+    let dot_git = root.join(".git");
+    if dot_git.is_dir() {
+        return Some(dot_git);
+    }
+    let raw = fs::read_to_string(&dot_git).ok()?;
+    let dir = PathBuf::from(raw.trim().strip_prefix("gitdir:")?.trim());
+    Some(if dir.is_absolute() {
+        dir
+    } else {
+        root.join(dir)
+    })
+}
+
+fn resolve_ref(git_dir: &Path, reference: &str) -> Option<String> {
+    // Linked worktrees keep branch refs in the common dir.
+    let common = fs::read_to_string(git_dir.join("commondir"))
+        .ok()
+        .map(|c| git_dir.join(c.trim()))
+        .unwrap_or_else(|| git_dir.to_path_buf());
+
+    for dir in [git_dir, common.as_path()] {
+        if let Ok(commit) = fs::read_to_string(dir.join(reference)) {
+            return Some(commit.trim().to_string());
+        }
+    }
+
+    let packed = fs::read_to_string(common.join("packed-refs")).ok()?;
+    packed.lines().find_map(|line| {
+        let (commit, name) = line.split_once(' ')?;
+        (name == reference).then(|| commit.to_string())
+    })
+}
+
+// Whether tracked files have uncommitted changes. `None` when git is unavailable.
+pub fn is_dirty(root: &Path) -> Option<bool> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(root)
+        .args(["status", "--porcelain", "--untracked-files=no"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(!output.stdout.is_empty())
+}
+
+// Branch and working tree state from `git status --porcelain=v2 --branch`.
+#[derive(Debug, Clone, Default)]
+pub struct RepoStatus {
+    // `None` for a detached HEAD.
+    pub branch: Option<String>,
+    pub upstream: Option<String>,
+    pub ahead: usize,
+    pub behind: usize,
+    // Tracked paths with staged or unstaged changes, including conflicts.
+    pub changed: Vec<String>,
+    pub untracked: Vec<String>,
+}
+
+pub fn status(root: &Path) -> Option<RepoStatus> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(root)
+        .args(["status", "--porcelain=v2", "--branch", "-z"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    Some(parse_status(&String::from_utf8_lossy(&output.stdout)))
+}
+
+// The NUL-separated output of `git status --porcelain=v2 --branch -z`.
+fn parse_status(text: &str) -> RepoStatus {
+    let mut out = RepoStatus::default();
+    let mut entries = text.split('\0').filter(|e| !e.is_empty());
+    while let Some(entry) = entries.next() {
+        if let Some(header) = entry.strip_prefix("# ") {
+            let (key, value) = header.split_once(' ').unwrap_or((header, ""));
+            match key {
+                "branch.head" if value != "(detached)" => out.branch = Some(value.to_string()),
+                "branch.upstream" => out.upstream = Some(value.to_string()),
+                "branch.ab" => {
+                    for n in value.split_whitespace() {
+                        if let Some(a) = n.strip_prefix('+') {
+                            out.ahead = a.parse().unwrap_or(0);
+                        } else if let Some(b) = n.strip_prefix('-') {
+                            out.behind = b.parse().unwrap_or(0);
+                        }
+                    }
+                }
+                _ => {}
+            }
+            continue;
+        }
+        // Ordinary (1), renamed/copied (2) and unmerged (u) entries end with the path;
+        // renames are followed by a separate NUL-terminated original path.
+        let fields = match entry.chars().next() {
+            Some('1') => 9,
+            Some('2') => 10,
+            Some('u') => 11,
+            Some('?') => {
+                out.untracked.push(entry[2..].to_string());
+                continue;
+            }
+            _ => continue,
+        };
+        if let Some(path) = entry.splitn(fields, ' ').nth(fields - 1) {
+            out.changed.push(path.to_string());
+        }
+        if entry.starts_with('2') {
+            entries.next();
+        }
+    }
+    out
+}
+
+// Tags pointing at HEAD.
+pub fn head_tags(root: &Path) -> Option<Vec<String>> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(root)
+        .args(["tag", "--points-at", "HEAD"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(
+        String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .map(|l| l.trim().to_string())
+            .filter(|l| !l.is_empty())
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn porcelain_v2_headers() {
+        let st = parse_status(
+            "# branch.oid 0123abcd\0# branch.head main\0# branch.upstream origin/main\0# branch.ab +2 -3\0",
+        );
+        assert_eq!(st.branch.as_deref(), Some("main"));
+        assert_eq!(st.upstream.as_deref(), Some("origin/main"));
+        assert_eq!((st.ahead, st.behind), (2, 3));
+
+        let st = parse_status("# branch.oid 0123abcd\0# branch.head (detached)\0");
+        assert_eq!(st.branch, None);
+        assert_eq!(st.upstream, None);
+    }
+
+    #[test]
+    fn porcelain_v2_entries() {
+        let text = [
+            "1 .M N... 100644 100644 100644 aaaa bbbb src/main.rs",
+            "1 A. N... 000000 100644 100644 0000 cccc dir with spaces/new file.txt",
+            "2 R. N... 100644 100644 100644 dddd dddd R100 docs/new.md",
+            "docs/old.md",
+            "u UU N... 100644 100644 100644 100644 eeee ffff 1111 Cargo.lock",
+            "? notes.txt",
+            "! target",
+        ]
+        .join("\0");
+        let st = parse_status(&format!("{text}\0"));
+        assert_eq!(
+            st.changed,
+            [
+                "src/main.rs",
+                "dir with spaces/new file.txt",
+                "docs/new.md",
+                "Cargo.lock"
+            ]
+        );
+        // The original path of a rename is not an entry of its own.
+        assert!(!st.changed.iter().any(|p| p == "docs/old.md"));
+        assert_eq!(st.untracked, ["notes.txt"]);
+    }
+}
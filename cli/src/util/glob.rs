@@ -0,0 +1,124 @@
+// Shell-style glob matching: `*` and `?` stop at `/`, `**` crosses directories,
+// `[abc]`/`[a-z]`/`[!x]` match one character from a class.
+pub fn glob_match(pattern: &str, text: &str) -> bool {
+    let p: Vec<char> = pattern.chars().collect();
+    let t: Vec<char> = text.chars().collect();
+    match_from(&p, &t)
+}
+
+fn match_from(p: &[char], t: &[char]) -> bool {
+    match p.first() {
+        None => t.is_empty(),
+        Some('*') if p.get(1) == Some(&'*') => {
+            let mut rest = &p[2..];
+            // `**/` also matches zero directories.
+            if rest.first() == Some(&'/') {
+                if match_from(&rest[1..], t) {
+                    return true;
+                }
+                rest = &rest[1..];
+                return (0..=t.len())
+                    .any(|i| (i == 0 || t[i - 1] == '/') && match_from(rest, &t[i..]));
+            }
+            (0..=t.len()).any(|i| match_from(rest, &t[i..]))
+        }
+        Some('*') => {
+            let rest = &p[1..];
+            for i in 0..=t.len() {
+                if match_from(rest, &t[i..]) {
+                    return true;
+                }
+                if i < t.len() && t[i] == '/' {
+                    break;
+                }
+            }
+            false
+        }
+        Some('?') => !t.is_empty() && t[0] != '/' && match_from(&p[1..], &t[1..]),
+        Some('[') => match (t.first(), class_end(p)) {
+            (Some(c), Some(end)) => {
+                *c != '/' && class_matches(&p[1..end], *c) && match_from(&p[end + 1..], &t[1..])
+            }
+            (Some(c), None) => *c == '[' && match_from(&p[1..], &t[1..]),
+            (None, _) => false,
+        },
+        Some('\\') if p.len() > 1 => t.first() == Some(&p[1]) && match_from(&p[2..], &t[1..]),
+        Some(c) => t.first() == Some(c) && match_from(&p[1..], &t[1..]),
+    }
+}
+
+fn class_end(p: &[char]) -> Option<usize> {
+    let start = if matches!(p.get(1), Some('!') | Some('^')) {
+        2
+    } else {
+        1
+    };
+    // A `]` right after the opening bracket is a literal member of the class.
+    (start + 1..p.len()).find(|&i| p[i] == ']')
+}
+
+fn class_matches(class: &[char], c: char) -> bool {
+    let (negate, class) = match class.first() {
+        Some('!') | Some('^') => (true, &class[1..]),
+        _ => (false, class),
+    };
+    let mut i = 0;
+    let mut found = false;
+    while i < class.len() {
+        if i + 2 < class.len() && class[i + 1] == '-' {
+            if class[i] <= c && c <= class[i + 2] {
+                found = true;
+            }
+            i += 3;
+        } else {
+            if class[i] == c {
+                found = true;
+            }
+            i += 1;
+        }
+    }
+    found != negate
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_star_and_question_mark_stay_in_one_segment() {
+        assert!(glob_match("*.rs", "main.rs"));
+        assert!(!glob_match("*.rs", "src/main.rs"));
+        assert!(glob_match("src/*", "src/main.rs"));
+        assert!(!glob_match("src/*", "src/bin/main.rs"));
+        assert!(glob_match("a?c", "abc"));
+        assert!(!glob_match("a?c", "a/c"));
+        assert!(!glob_match("a?c", "ac"));
+        assert!(glob_match("*", ""));
+    }
+
+    #[test]
+    fn double_star_crosses_directories() {
+        assert!(glob_match("src/**/*.rs", "src/main.rs"));
+        assert!(glob_match("src/**/*.rs", "src/a/b/c.rs"));
+        assert!(!glob_match("src/**/*.rs", "srcx/a.rs"));
+        assert!(glob_match("**/target", "target"));
+        assert!(glob_match("**/target", "a/b/target"));
+        assert!(!glob_match("**/target", "a/xtarget"));
+        assert!(glob_match("a/**", "a/b/c"));
+        assert!(glob_match("**", "a/b"));
+        assert!(glob_match("**.md", "docs/a.md"));
+    }
+
+    #[test]
+    fn classes_and_escapes() {
+        assert!(glob_match("[a-c]x", "bx"));
+        assert!(!glob_match("[a-c]x", "dx"));
+        assert!(glob_match("[!a]x", "bx"));
+        assert!(!glob_match("[^a]x", "ax"));
+        assert!(glob_match("[]]", "]"));
+        assert!(!glob_match("[/]", "/"));
+        assert!(glob_match("[abc", "[abc"));
+        assert!(glob_match("\\*", "*"));
+        assert!(!glob_match("\\*", "a"));
+    }
+}
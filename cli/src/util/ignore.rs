@@ -0,0 +1,211 @@
+use std::fs;
+use std::path::Path;
+
+use super::glob::glob_match;
+
+#[derive(Debug, Clone)]
+struct Rule {
+    pattern: String,
+    negate: bool,
+    dir_only: bool,
+    anchored: bool,
+}
+
+#[derive(Debug, Clone)]
+struct IgnoreFile {
+    // Directory of the ignore file relative to the root ("" for the root).
+    base: String,
+    rules: Vec<Rule>,
+}
+
+// A stack of gitignore-style files. Paths are relative to the root and use `/`.
+#[derive(Debug, Clone, Default)]
+pub struct Ignores {
+    files: Vec<IgnoreFile>,
+}
+
+impl Ignores {
+    pub fn new() -> Ignores {
+        Ignores::default()
+    }
+
+    // Loads `names` (e.g. `.gitignore`) from the root and every directory leading to `rel`.
+    pub fn for_path(root: &Path, rel: &str, names: &[&str]) -> Ignores {
+        let mut out = Ignores::new();
+        out.load_dir(root, "", names);
+        let parts: Vec<&str> = rel.split('/').filter(|s| !s.is_empty()).collect();
+        for i in 1..parts.len() {
+            out.load_dir(root, &parts[..i].join("/"), names);
+        }
+        out
+    }
+
+    pub fn load_dir(&mut self, root: &Path, dir: &str, names: &[&str]) -> bool {
+        let mut loaded = false;
+        for name in names {
+            if let Ok(content) = fs::read_to_string(root.join(dir).join(name)) {
+                self.add(dir, &content);
+                loaded = true;
+            }
+        }
+        loaded
+    }
+
+    pub fn add(&mut self, base: &str, content: &str) {
+        let rules = content.lines().filter_map(parse_rule).collect();
+        self.files.push(IgnoreFile {
+            base: base.trim_matches('/').to_string(),
+            rules,
+        });
+    }
+
+    // Git cannot re-include a path whose parent directory is excluded, so every
+    // ancestor is checked before the path itself.
+    pub fn is_ignored(&self, rel: &str, is_dir: bool) -> bool {
+        let parts: Vec<&str> = rel.split('/').filter(|s| !s.is_empty()).collect();
+        for i in 1..=parts.len() {
+            let prefix = parts[..i].join("/");
+            let prefix_is_dir = i < parts.len() || is_dir;
+            if self.matches(&prefix, prefix_is_dir) {
+                return true;
+            }
+        }
+        false
+    }
+
+    fn matches(&self, rel: &str, is_dir: bool) -> bool {
+        let mut ignored = false;
+        for file in &self.files {
+            let local = if file.base.is_empty() {
+                rel
+            } else {
+                match rel
+                    .strip_prefix(file.base.as_str())
+                    .and_then(|r| r.strip_prefix('/'))
+                {
+                    Some(r) => r,
+                    None => continue,
+                }
+            };
+            for rule in &file.rules {
+                if rule.dir_only && !is_dir {
+                    continue;
+                }
+                let hit = if rule.anchored {
+                    glob_match(&rule.pattern, local)
+                } else {
+                    let name = local.rsplit('/').next().unwrap_or(local);
+                    glob_match(&rule.pattern, name)
+                };
+                if hit {
+                    ignored = !rule.negate;
+                }
+            }
+        }
+        ignored
+    }
+}
+
+fn parse_rule(line: &str) -> Option<Rule> {
+    let line = line.trim_end();
+    if line.is_empty() || line.starts_with('#') {
+        return None;
+    }
+
+    let (negate, line) = match line.strip_prefix('!') {
+        Some(rest) => (true, rest),
+        None => (false, line.strip_prefix('\\').unwrap_or(line)),
+    };
+    let (dir_only, line) = match line.strip_suffix('/') {
+        Some(rest) => (true, rest),
+        None => (false, line),
+    };
+    let anchored = line.contains('/');
+    let pattern = line.trim_start_matches('/').to_string();
+    if pattern.is_empty() {
+        return None;
+    }
+
+    Some(Rule {
+        pattern,
+        negate,
+        dir_only,
+        anchored,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rules(content: &str) -> Ignores {
+        let mut ignores = Ignores::new();
+        ignores.add("", content);
+        ignores
+    }
+
+    #[test]
+    fn negation_reincludes_unless_a_parent_is_excluded() {
+        let ig = rules("*.log\n!keep.log\nlogs/\n!logs/keep.log\n");
+        assert!(ig.is_ignored("a.log", false));
+        assert!(ig.is_ignored("deep/dir/a.log", false));
+        assert!(!ig.is_ignored("keep.log", false));
+        assert!(!ig.is_ignored("deep/keep.log", false));
+        assert!(ig.is_ignored("logs/keep.log", false));
+    }
+
+    #[test]
+    fn leading_or_inner_slash_anchors_to_the_base() {
+        let ig = rules("/build\ndocs/**/*.md\n");
+        assert!(ig.is_ignored("build", true));
+        assert!(ig.is_ignored("build/out.js", false));
+        assert!(!ig.is_ignored("src/build", true));
+        assert!(ig.is_ignored("docs/a.md", false));
+        assert!(ig.is_ignored("docs/x/y.md", false));
+        assert!(!ig.is_ignored("a/docs/b.md", false));
+    }
+
+    #[test]
+    fn trailing_slash_only_matches_directories() {
+        let ig = rules("dist/\n");
+        assert!(ig.is_ignored("dist", true));
+        assert!(ig.is_ignored("apps/fe/dist/index.js", false));
+        assert!(!ig.is_ignored("dist", false));
+    }
+
+    #[test]
+    fn comments_escapes_and_blank_lines() {
+        let ig = rules("# comment\n\n\\#hash\n\\!bang\n/\n");
+        assert!(ig.is_ignored("#hash", false));
+        assert!(ig.is_ignored("!bang", false));
+        assert!(!ig.is_ignored("# comment", false));
+        assert!(!ig.is_ignored("other", false));
+    }
+
+    #[test]
+    fn nested_files_apply_below_their_directory_and_win() {
+        let mut ig = rules("*.env\n");
+        ig.add("apps/be/", "!.env\n*.tmp\n");
+        assert!(!ig.is_ignored("apps/be/.env", false));
+        assert!(ig.is_ignored("apps/fe/.env", false));
+        assert!(ig.is_ignored("apps/be/x.tmp", false));
+        assert!(!ig.is_ignored("x.tmp", false));
+        assert!(!ig.is_ignored("apps/bex/x.tmp", false));
+    }
+
+    #[test]
+    fn for_path_loads_every_directory_on_the_way() {
+        let root = std::env::temp_dir().join(format!("ignore-for-path-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("apps/fe")).unwrap();
+        fs::write(root.join(".gitignore"), "*.log\n").unwrap();
+        fs::write(root.join("apps/.ignore"), "cache/\n").unwrap();
+        fs::write(root.join("apps/fe/.gitignore"), "!debug.log\n").unwrap();
+
+        let ig = Ignores::for_path(&root, "apps/fe/debug.log", &[".gitignore", ".ignore"]);
+        assert!(!ig.is_ignored("apps/fe/debug.log", false));
+        assert!(ig.is_ignored("apps/fe/cache", true));
+        assert!(ig.is_ignored("apps/other.log", false));
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
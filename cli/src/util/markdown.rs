@@ -0,0 +1,182 @@
+use regex::Regex;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Heading {
+    pub level: usize,
+    pub text: String,
+    // 1-based.
+    pub line: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Link {
+    pub target: String,
+    pub line: usize,
+    pub column: usize,
+}
+
+// Lines outside fenced code blocks, with their 1-based numbers.
+fn prose_lines(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    let mut fence: Option<&str> = None;
+    text.lines().enumerate().filter_map(move |(i, line)| {
+        let trimmed = line.trim_start();
+        let marker = ["```", "~~~"].into_iter().find(|m| trimmed.starts_with(m));
+        match (fence, marker) {
+            (None, Some(m)) => {
+                fence = Some(m);
+                None
+            }
+            (Some(open), Some(m)) if open == m => {
+                fence = None;
+                None
+            }
+            (Some(_), _) => None,
+            (None, None) => Some((i + 1, line)),
+        }
+    })
+}
+
+// ATX headings (`## Title`), skipping code blocks.
+pub fn headings(text: &str) -> Vec<Heading> {
+    prose_lines(text)
+        .filter_map(|(line, raw)| {
+            let trimmed = raw.trim_start();
+            let level = trimmed.chars().take_while(|c| *c == '#').count();
+            if level == 0 || level > 6 {
+                return None;
+            }
+            let rest = &trimmed[level..];
+            if !rest.is_empty() && !rest.starts_with(' ') {
+                return None;
+            }
+            let text = rest.trim().trim_end_matches('#').trim().to_string();
+            Some(Heading { level, text, line })
+        })
+        .collect()
+}
+
+// GitHub-style anchor for a heading: lowercase, punctuation dropped, spaces to hyphens.
+pub fn slug(heading: &str) -> String {
+    let plain = Regex::new(r"\[([^\]]*)\]\([^)]*\)")
+        .expect("valid link pattern")
+        .replace_all(heading, "$1");
+    plain
+        .to_lowercase()
+        .chars()
+        .filter_map(|c| match c {
+            ' ' => Some('-'),
+            '-' | '_' => Some(c),
+            c if c.is_alphanumeric() => Some(c),
+            _ => None,
+        })
+        .collect()
+}
+
+// Anchors of every heading, with GitHub's `-1`, `-2` suffixes for repeats.
+pub fn anchors(text: &str) -> Vec<String> {
+    let mut out: Vec<String> = Vec::new();
+    for h in headings(text) {
+        let base = slug(&h.text);
+        let mut anchor = base.clone();
+        let mut n = 0;
+        while out.contains(&anchor) {
+            n += 1;
+            anchor = format!("{base}-{n}");
+        }
+        out.push(anchor);
+    }
+    out
+}
+
+// Inline links and images, `[text](target)`, outside code blocks and code spans.
+pub fn links(text: &str) -> Vec<Link> {
+    let link_re =
+        Regex::new(r#"\]\(\s*<?([^)\s>]+)>?(?:\s+"[^"]*")?\s*\)"#).expect("valid link pattern");
+    let code_re = Regex::new(r"`[^`]*`").expect("valid code span pattern");
+    let mut out = Vec::new();
+    for (line, raw) in prose_lines(text) {
+        // Blank out code spans so their contents cannot look like links.
+        let masked = code_re.replace_all(raw, |c: &regex::Captures| " ".repeat(c[0].len()));
+        for c in link_re.captures_iter(&masked) {
+            let m = c.get(1).expect("target group");
+            out.push(Link {
+                target: m.as_str().to_string(),
+                line,
+                column: masked[..m.start()].chars().count() + 1,
+            });
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOC: &str = "\
+# Title
+Intro with [a link](./other.md#setup \"Title\") and `[not](a-link.md)`.
+
+## Setup ##
+#not-a-heading
+####### too deep
+
+```sh
+# a shell comment
+[also not](a-link.md)
+```
+
+~~~
+## Fenced
+~~~
+### [Linked](https://example.com) heading: v2.0!
+## Setup
+![logo](<img/logo.png>)
+";
+
+    #[test]
+    fn headings_skip_code_blocks() {
+        let found = headings(DOC);
+        let found: Vec<(usize, &str, usize)> = found
+            .iter()
+            .map(|h| (h.level, h.text.as_str(), h.line))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                (1, "Title", 1),
+                (2, "Setup", 4),
+                (3, "[Linked](https://example.com) heading: v2.0!", 16),
+                (2, "Setup", 17),
+            ]
+        );
+    }
+
+    #[test]
+    fn anchors_follow_github_slugs() {
+        assert_eq!(slug("Backend process types"), "backend-process-types");
+        assert_eq!(slug("CLI (`monorepo-cli`)"), "cli-monorepo-cli");
+        assert_eq!(slug("설치 방법"), "설치-방법");
+        assert_eq!(
+            anchors(DOC),
+            ["title", "setup", "linked-heading-v20", "setup-1"]
+        );
+    }
+
+    #[test]
+    fn links_skip_code() {
+        let found = links(DOC);
+        let found: Vec<(&str, usize, usize)> = found
+            .iter()
+            .map(|l| (l.target.as_str(), l.line, l.column))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                ("./other.md#setup", 2, 21),
+                ("https://example.com", 16, 14),
+                ("img/logo.png", 18, 10),
+            ]
+        );
+    }
+}
@@ -1,4 +1,17 @@
+pub mod bunlock;
 pub mod command;
+pub mod dockerfile;
 pub mod env;
+pub mod file;
+pub mod git;
+pub mod glob;
+pub mod ignore;
+pub mod markdown;
+pub mod nginx;
+pub mod path;
 pub mod repo;
+pub mod semver;
+pub mod systemd;
+pub mod time;
+pub mod tls;
 pub mod walk;
@@ -0,0 +1,57 @@
+// Minimal nginx config reading: directives are matched per line, which is enough for
+// the hand-written configs this repo ships (one directive per line).
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Directive {
+    pub args: Vec<String>,
+    // 1-based.
+    pub line: usize,
+}
+
+// Every `name arg...;` directive, ignoring comments.
+pub fn directives(text: &str, name: &str) -> Vec<Directive> {
+    let mut out = Vec::new();
+    for (i, line) in text.lines().enumerate() {
+        let line = line.split('#').next().unwrap_or_default().trim();
+        for stmt in line.split([';', '{', '}']) {
+            let mut words = stmt.split_whitespace();
+            if words.next() != Some(name) {
+                continue;
+            }
+            let args = words
+                .map(|w| w.trim_matches(|c| c == '"' || c == '\'').to_string())
+                .collect();
+            out.push(Directive { args, line: i + 1 });
+        }
+    }
+    out
+}
+
+// Line ranges (1-based, inclusive) of `server { ... }` blocks.
+pub fn server_blocks(text: &str) -> Vec<(usize, usize)> {
+    let mut out = Vec::new();
+    // One entry per open block: its first line when it is a server block.
+    let mut stack: Vec<Option<usize>> = Vec::new();
+    let mut stmt = String::new();
+    for (i, line) in text.lines().enumerate() {
+        let line = line.split('#').next().unwrap_or_default();
+        stmt.push(' ');
+        for c in line.chars() {
+            match c {
+                '{' => {
+                    stack.push((stmt.trim() == "server").then_some(i + 1));
+                    stmt.clear();
+                }
+                '}' => {
+                    if let Some(Some(start)) = stack.pop() {
+                        out.push((start, i + 1));
+                    }
+                    stmt.clear();
+                }
+                ';' => stmt.clear(),
+                c => stmt.push(c),
+            }
+        }
+    }
+    out
+}
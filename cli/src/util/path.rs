@@ -0,0 +1,11 @@
+use std::path::Path;
+
+// A path with forward slashes, as shown in findings on every platform.
+pub fn slash(p: &Path) -> String {
+    p.to_string_lossy().replace('\\', "/")
+}
+
+// `p` relative to `root` with forward slashes; unchanged when it lies outside `root`.
+pub fn rel_str(root: &Path, p: &Path) -> String {
+    slash(p.strip_prefix(root).unwrap_or(p))
+}
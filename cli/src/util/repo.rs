@@ -1,10 +1,25 @@
-use std::path::Path;
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
 
-use super::walk::walk_files;
+use super::file::{FileError, read_json};
+use super::walk::{Walk, walk};
 
-pub fn has_repo_nginx_config(root: &Path) -> bool {
-    let files = walk_files(root, 5_000);
-    files.iter().any(|p| {
+// Values of PROCESS_TYPE understood by apps/be/src/config/process.ts.
+pub const BACKEND_PROCESS_TYPES: [&str; 3] = ["main", "admin", "worker"];
+
+// Files looked at when searching the repo for nginx config.
+pub const NGINX_SEARCH_LIMIT: usize = 5_000;
+
+// `*nginx*.conf` files and anything under an `nginx/` directory.
+pub fn nginx_config_files(root: &Path) -> Vec<PathBuf> {
+    find_nginx_config(root).files
+}
+
+// Like `nginx_config_files`, keeping whether the search stopped at NGINX_SEARCH_LIMIT.
+pub fn find_nginx_config(root: &Path) -> Walk {
+    let mut found = walk(root, NGINX_SEARCH_LIMIT);
+    found.files.retain(|p| {
         let file_name = p
             .file_name()
             .map(|s| s.to_string_lossy().to_lowercase())
@@ -14,8 +29,12 @@ pub fn has_repo_nginx_config(root: &Path) -> bool {
             return true;
         }
 
-        p.components().any(|c| c.as_os_str().to_string_lossy().to_lowercase() == "nginx")
-    })
+        p.strip_prefix(root)
+            .unwrap_or(p)
+            .components()
+            .any(|c| c.as_os_str().to_string_lossy().to_lowercase() == "nginx")
+    });
+    found
 }
 
 pub fn has_pm2_ecosystem(root: &Path) -> bool {
@@ -28,3 +47,46 @@ pub fn has_pm2_ecosystem(root: &Path) -> bool {
     ];
     candidates.iter().any(|p| p.exists())
 }
+
+// Workspace package directories relative to the root (the root itself is ""),
+// expanded from the root package.json `workspaces` globs (`apps/*`, `packages/*`).
+// A missing root package.json has no workspaces; an unreadable or invalid one is an error.
+pub fn workspace_dirs(root: &Path) -> Result<Vec<String>, FileError> {
+    let mut out = vec![String::new()];
+
+    let manifest = read_json(&root.join("package.json"))?.unwrap_or(Value::Null);
+    let patterns = match &manifest["workspaces"] {
+        Value::Array(v) => v.clone(),
+        Value::Object(o) => o
+            .get("packages")
+            .and_then(|v| v.as_array())
+            .cloned()
+            .unwrap_or_default(),
+        _ => Vec::new(),
+    };
+
+    for pattern in patterns.iter().filter_map(|v| v.as_str()) {
+        let pattern = pattern.trim_end_matches('/');
+        match pattern.strip_suffix("/*") {
+            Some(parent) => {
+                let mut dirs: Vec<String> = fs::read_dir(root.join(parent))
+                    .into_iter()
+                    .flatten()
+                    .flatten()
+                    .filter(|e| e.path().join("package.json").is_file())
+                    .map(|e| format!("{}/{}", parent, e.file_name().to_string_lossy()))
+                    .collect();
+                dirs.sort();
+                out.extend(dirs);
+            }
+            None => {
+                if root.join(pattern).join("package.json").is_file() {
+                    out.push(pattern.to_string());
+                }
+            }
+        }
+    }
+
+    out.dedup();
+    Ok(out)
+}
@@ -0,0 +1,370 @@
+use std::cmp::Ordering;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Version {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+    pub pre: Vec<String>,
+}
+
+impl Version {
+    pub fn parse(input: &str) -> Option<Version> {
+        let input = input.trim().trim_start_matches('=').trim_start_matches('v');
+        let input = input.split('+').next().unwrap_or_default();
+        let (core, pre) = match input.split_once('-') {
+            Some((c, p)) => (c, p.split('.').map(|s| s.to_string()).collect()),
+            None => (input, Vec::new()),
+        };
+        let mut parts = core.split('.');
+        let major = parts.next()?.parse().ok()?;
+        let minor = parts.next()?.parse().ok()?;
+        let patch = parts.next()?.parse().ok()?;
+        if parts.next().is_some() {
+            return None;
+        }
+        Some(Version {
+            major,
+            minor,
+            patch,
+            pre,
+        })
+    }
+
+    fn core(&self) -> (u64, u64, u64) {
+        (self.major, self.minor, self.patch)
+    }
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.core().cmp(&other.core()).then_with(|| {
+            match (self.pre.is_empty(), other.pre.is_empty()) {
+                (true, true) => Ordering::Equal,
+                (true, false) => Ordering::Greater,
+                (false, true) => Ordering::Less,
+                (false, false) => cmp_pre(&self.pre, &other.pre),
+            }
+        })
+    }
+}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn cmp_pre(a: &[String], b: &[String]) -> Ordering {
+    for (x, y) in a.iter().zip(b.iter()) {
+        let ord = match (x.parse::<u64>(), y.parse::<u64>()) {
+            (Ok(x), Ok(y)) => x.cmp(&y),
+            (Ok(_), Err(_)) => Ordering::Less,
+            (Err(_), Ok(_)) => Ordering::Greater,
+            (Err(_), Err(_)) => x.cmp(y),
+        };
+        if ord != Ordering::Equal {
+            return ord;
+        }
+    }
+    a.len().cmp(&b.len())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Lt,
+    Lte,
+    Gt,
+    Gte,
+    Eq,
+}
+
+#[derive(Debug, Clone)]
+struct Comparator {
+    op: Op,
+    version: Version,
+}
+
+impl Comparator {
+    fn matches(&self, v: &Version) -> bool {
+        match self.op {
+            Op::Lt => v < &self.version,
+            Op::Lte => v <= &self.version,
+            Op::Gt => v > &self.version,
+            Op::Gte => v >= &self.version,
+            Op::Eq => v == &self.version,
+        }
+    }
+}
+
+// A partially specified version such as `5`, `1.x` or `2.3.*`.
+struct Partial {
+    major: Option<u64>,
+    minor: Option<u64>,
+    patch: Option<u64>,
+    pre: Vec<String>,
+}
+
+impl Partial {
+    fn parse(input: &str) -> Option<Partial> {
+        let input = input.trim().trim_start_matches('v');
+        let input = input.split('+').next().unwrap_or_default();
+        let (core, pre) = match input.split_once('-') {
+            Some((c, p)) => (c, p.split('.').map(|s| s.to_string()).collect()),
+            None => (input, Vec::new()),
+        };
+        let mut nums = [None, None, None];
+        for (i, part) in core.split('.').enumerate() {
+            if i >= 3 {
+                return None;
+            }
+            if matches!(part, "x" | "X" | "*" | "") {
+                break;
+            }
+            nums[i] = Some(part.parse().ok()?);
+        }
+        Some(Partial {
+            major: nums[0],
+            minor: nums[1],
+            patch: nums[2],
+            pre,
+        })
+    }
+
+    fn floor(&self) -> Version {
+        Version {
+            major: self.major.unwrap_or(0),
+            minor: self.minor.unwrap_or(0),
+            patch: self.patch.unwrap_or(0),
+            pre: self.pre.clone(),
+        }
+    }
+}
+
+fn ver(major: u64, minor: u64, patch: u64) -> Version {
+    Version {
+        major,
+        minor,
+        patch,
+        pre: vec!["0".to_string()],
+    }
+}
+
+fn cmp(op: Op, version: Version) -> Comparator {
+    Comparator { op, version }
+}
+
+#[derive(Debug, Clone)]
+pub struct Range {
+    sets: Vec<Vec<Comparator>>,
+}
+
+impl Range {
+    pub fn parse(input: &str) -> Option<Range> {
+        let mut sets = Vec::new();
+        for alt in input.split("||") {
+            sets.push(parse_set(alt.trim())?);
+        }
+        Some(Range { sets })
+    }
+
+    pub fn matches(&self, v: &Version) -> bool {
+        self.sets.iter().any(|set| {
+            if !set.iter().all(|c| c.matches(v)) {
+                return false;
+            }
+            // Prereleases only match when a comparator opts into the same x.y.z tuple.
+            v.pre.is_empty()
+                || set
+                    .iter()
+                    .any(|c| !c.version.pre.is_empty() && c.version.core() == v.core())
+        })
+    }
+}
+
+fn parse_set(input: &str) -> Option<Vec<Comparator>> {
+    if input.is_empty() || input == "*" || input == "x" || input == "latest" {
+        return Some(vec![cmp(Op::Gte, ver(0, 0, 0))]);
+    }
+
+    let tokens = tokenize(input);
+    if tokens.len() == 3 && tokens[1] == "-" {
+        let lo = Partial::parse(&tokens[0])?;
+        let hi = Partial::parse(&tokens[2])?;
+        let mut out = vec![cmp(Op::Gte, lo.floor())];
+        out.push(match (hi.major, hi.minor, hi.patch) {
+            (Some(_), Some(_), Some(_)) => cmp(Op::Lte, hi.floor()),
+            (Some(m), Some(n), None) => cmp(Op::Lt, ver(m, n + 1, 0)),
+            (Some(m), None, _) => cmp(Op::Lt, ver(m + 1, 0, 0)),
+            _ => cmp(Op::Gte, ver(0, 0, 0)),
+        });
+        return Some(out);
+    }
+
+    let mut out = Vec::new();
+    for t in tokens {
+        out.extend(parse_comparator(&t)?);
+    }
+    Some(out)
+}
+
+fn tokenize(input: &str) -> Vec<String> {
+    // Join operators separated from their version by whitespace (`>= 1.2.3`).
+    let mut out: Vec<String> = Vec::new();
+    for part in input.split_whitespace() {
+        match out.last_mut() {
+            Some(prev) if matches!(prev.as_str(), ">" | ">=" | "<" | "<=" | "=" | "^" | "~") => {
+                prev.push_str(part)
+            }
+            _ => out.push(part.to_string()),
+        }
+    }
+    out
+}
+
+fn parse_comparator(input: &str) -> Option<Vec<Comparator>> {
+    let (op, rest) = ["^", "~>", "~", ">=", "<=", ">", "<", "="]
+        .iter()
+        .find_map(|op| input.strip_prefix(op).map(|rest| (*op, rest)))
+        .unwrap_or(("", input));
+
+    let p = Partial::parse(rest)?;
+    let floor = p.floor();
+
+    let out = match op {
+        "^" => {
+            let upper = match (p.major, p.minor, p.patch) {
+                (Some(0), Some(0), Some(z)) => ver(0, 0, z + 1),
+                (Some(0), Some(n), _) => ver(0, n + 1, 0),
+                (Some(0), None, _) => ver(1, 0, 0),
+                (Some(m), _, _) => ver(m + 1, 0, 0),
+                (None, _, _) => return Some(vec![cmp(Op::Gte, ver(0, 0, 0))]),
+            };
+            vec![cmp(Op::Gte, floor), cmp(Op::Lt, upper)]
+        }
+        "~" | "~>" => {
+            let upper = match (p.major, p.minor) {
+                (Some(m), Some(n)) => ver(m, n + 1, 0),
+                (Some(m), None) => ver(m + 1, 0, 0),
+                (None, _) => return Some(vec![cmp(Op::Gte, ver(0, 0, 0))]),
+            };
+            vec![cmp(Op::Gte, floor), cmp(Op::Lt, upper)]
+        }
+        ">=" => vec![cmp(Op::Gte, floor)],
+        "<" => vec![cmp(Op::Lt, floor)],
+        ">" => match (p.major, p.minor, p.patch) {
+            (Some(_), Some(_), Some(_)) => vec![cmp(Op::Gt, floor)],
+            (Some(m), Some(n), None) => vec![cmp(Op::Gte, ver(m, n + 1, 0))],
+            (Some(m), None, _) => vec![cmp(Op::Gte, ver(m + 1, 0, 0))],
+            (None, _, _) => vec![cmp(Op::Lt, ver(0, 0, 0))],
+        },
+        "<=" => match (p.major, p.minor, p.patch) {
+            (Some(_), Some(_), Some(_)) => vec![cmp(Op::Lte, floor)],
+            (Some(m), Some(n), None) => vec![cmp(Op::Lt, ver(m, n + 1, 0))],
+            (Some(m), None, _) => vec![cmp(Op::Lt, ver(m + 1, 0, 0))],
+            (None, _, _) => vec![cmp(Op::Gte, ver(0, 0, 0))],
+        },
+        _ => match (p.major, p.minor, p.patch) {
+            (Some(_), Some(_), Some(_)) => vec![cmp(Op::Eq, floor)],
+            (Some(m), Some(n), None) => {
+                vec![cmp(Op::Gte, floor), cmp(Op::Lt, ver(m, n + 1, 0))]
+            }
+            (Some(m), None, _) => vec![cmp(Op::Gte, floor), cmp(Op::Lt, ver(m + 1, 0, 0))],
+            (None, _, _) => vec![cmp(Op::Gte, ver(0, 0, 0))],
+        },
+    };
+    Some(out)
+}
+
+pub fn satisfies(version: &str, range: &str) -> Option<bool> {
+    let v = Version::parse(version)?;
+    let r = Range::parse(range)?;
+    Some(r.matches(&v))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn v(s: &str) -> Version {
+        Version::parse(s).unwrap()
+    }
+
+    #[test]
+    fn parses_full_versions_only() {
+        assert_eq!(v("v1.2.3"), v("1.2.3"));
+        assert_eq!(v("=1.2.3+build.5"), v("1.2.3"));
+        assert_eq!(v("1.2.3-rc.1").pre, ["rc", "1"]);
+        for bad in ["1.2", "1.2.3.4", "1.2.x", "", "a.b.c"] {
+            assert_eq!(Version::parse(bad), None, "{bad}");
+        }
+    }
+
+    #[test]
+    fn orders_prereleases_before_the_release() {
+        let ordered = [
+            "1.0.0-alpha",
+            "1.0.0-alpha.1",
+            "1.0.0-alpha.beta",
+            "1.0.0-beta",
+            "1.0.0-beta.2",
+            "1.0.0-beta.11",
+            "1.0.0-rc.1",
+            "1.0.0",
+            "1.0.1-0",
+        ];
+        for pair in ordered.windows(2) {
+            assert!(v(pair[0]) < v(pair[1]), "{} < {}", pair[0], pair[1]);
+        }
+    }
+
+    #[test]
+    fn caret_and_tilde_ranges() {
+        let cases = [
+            ("^1.2.3", "1.9.0", true),
+            ("^1.2.3", "1.2.2", false),
+            ("^1.2.3", "2.0.0", false),
+            ("^0.2.3", "0.2.9", true),
+            ("^0.2.3", "0.3.0", false),
+            ("^0.0.3", "0.0.3", true),
+            ("^0.0.3", "0.0.4", false),
+            ("~1.2.3", "1.2.9", true),
+            ("~1.2.3", "1.3.0", false),
+            ("~1", "1.9.0", true),
+            ("~> 1.2", "1.2.5", true),
+        ];
+        for (range, version, expected) in cases {
+            assert_eq!(
+                satisfies(version, range),
+                Some(expected),
+                "{version} in {range}"
+            );
+        }
+    }
+
+    #[test]
+    fn prereleases_need_a_comparator_on_the_same_tuple() {
+        assert_eq!(satisfies("1.2.3-beta.2", "^1.2.3-beta.1"), Some(true));
+        assert_eq!(satisfies("1.2.3", "^1.2.3-beta.1"), Some(true));
+        assert_eq!(satisfies("1.2.4-alpha", "^1.2.3-beta.1"), Some(false));
+        assert_eq!(satisfies("1.5.0-rc.1", ">=1.0.0"), Some(false));
+        assert_eq!(satisfies("2.0.0-rc.1", "^1.0.0"), Some(false));
+    }
+
+    #[test]
+    fn hyphen_unions_and_partial_ranges() {
+        assert_eq!(satisfies("1.2.0", "1.2 - 2.3"), Some(true));
+        assert_eq!(satisfies("2.3.9", "1.2 - 2.3"), Some(true));
+        assert_eq!(satisfies("2.4.0", "1.2 - 2.3"), Some(false));
+        assert_eq!(satisfies("3.1.0", "^1 || ^3"), Some(true));
+        assert_eq!(satisfies("2.0.0", "^1 || ^3"), Some(false));
+        assert_eq!(satisfies("1.9.9", ">= 1.2.3 < 2"), Some(true));
+        assert_eq!(satisfies("2.0.0", ">= 1.2.3 < 2"), Some(false));
+        assert_eq!(satisfies("1.4.0", "1.x"), Some(true));
+        assert_eq!(satisfies("1.3.0", ">1.2"), Some(true));
+        assert_eq!(satisfies("1.2.9", ">1.2"), Some(false));
+        assert_eq!(satisfies("0.0.1", "*"), Some(true));
+        assert_eq!(satisfies("nope", "^1"), None);
+        assert_eq!(satisfies("1.0.0", "^a.b"), None);
+    }
+}
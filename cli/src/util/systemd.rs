@@ -0,0 +1,138 @@
+use std::collections::BTreeMap;
+
+// One `Key=Value` assignment of a unit file, with its 1-based line.
+#[derive(Debug, Clone)]
+pub struct Entry {
+    pub section: String,
+    pub key: String,
+    pub value: String,
+    pub line: usize,
+}
+
+// A parsed systemd unit. Keys may repeat (`Environment=` is cumulative), so entries keep file order.
+#[derive(Debug, Clone, Default)]
+pub struct Unit {
+    pub entries: Vec<Entry>,
+}
+
+impl Unit {
+    pub fn parse(text: &str) -> Unit {
+        let mut entries = Vec::new();
+        let mut section = String::new();
+        let mut pending: Option<(String, String, usize)> = None;
+
+        for (i, raw) in text.lines().enumerate() {
+            // Backslash continuations join the next line with a space.
+            if let Some((key, mut value, line)) = pending.take() {
+                let part = raw.trim();
+                match part.strip_suffix('\\') {
+                    Some(rest) => {
+                        value.push(' ');
+                        value.push_str(rest.trim());
+                        pending = Some((key, value, line));
+                    }
+                    None => {
+                        value.push(' ');
+                        value.push_str(part);
+                        entries.push(Entry {
+                            section: section.clone(),
+                            key,
+                            value,
+                            line,
+                        });
+                    }
+                }
+                continue;
+            }
+
+            let line = raw.trim();
+            if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+                continue;
+            }
+            if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                section = name.trim().to_string();
+                continue;
+            }
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            let key = key.trim().to_string();
+            let value = value.trim();
+            match value.strip_suffix('\\') {
+                Some(rest) => pending = Some((key, rest.trim().to_string(), i + 1)),
+                None => entries.push(Entry {
+                    section: section.clone(),
+                    key,
+                    value: value.to_string(),
+                    line: i + 1,
+                }),
+            }
+        }
+        if let Some((key, value, line)) = pending {
+            entries.push(Entry {
+                section,
+                key,
+                value,
+                line,
+            });
+        }
+        Unit { entries }
+    }
+
+    // The last assignment wins, as in systemd.
+    pub fn get(&self, section: &str, key: &str) -> Option<&Entry> {
+        self.entries
+            .iter()
+            .rev()
+            .find(|e| e.section == section && e.key == key)
+    }
+
+    // Variables from every `Environment=` line of `[Service]`, with the line that set them.
+    pub fn environment(&self) -> BTreeMap<String, (String, usize)> {
+        let mut out = BTreeMap::new();
+        for e in self
+            .entries
+            .iter()
+            .filter(|e| e.section == "Service" && e.key == "Environment")
+        {
+            for word in split_words(&e.value) {
+                if let Some((k, v)) = word.split_once('=') {
+                    out.insert(k.to_string(), (v.to_string(), e.line));
+                }
+            }
+        }
+        out
+    }
+}
+
+// Splits on whitespace, keeping double- or single-quoted words together and dropping the quotes.
+pub fn split_words(value: &str) -> Vec<String> {
+    let mut out = Vec::new();
+    let mut word = String::new();
+    let mut quote = None;
+    let mut started = false;
+    for c in value.chars() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), c) => word.push(c),
+            (None, '"' | '\'') => {
+                quote = Some(c);
+                started = true;
+            }
+            (None, c) if c.is_whitespace() => {
+                if started {
+                    out.push(std::mem::take(&mut word));
+                    started = false;
+                }
+            }
+            (None, c) => {
+                word.push(c);
+                started = true;
+            }
+        }
+    }
+    if started {
+        out.push(word);
+    }
+    out
+}
@@ -1,39 +1,201 @@
 use std::fs;
 use std::path::{Path, PathBuf};
 
+use super::ignore::Ignores;
+
+// Ignore files honoured by `walk`, in the order git applies them.
+const IGNORE_FILES: [&str; 2] = [".gitignore", ".ignore"];
+
+// Never source, and large enough to exhaust `max_files` when a checkout forgets to ignore them
+// (`target` is Cargo's build output).
+const ALWAYS_SKIPPED: [&str; 3] = [".git", "node_modules", "target"];
+
+/// Files under a root, sorted by path, and how the walk ended.
+#[derive(Debug, Clone, Default)]
+pub struct Walk {
+    pub files: Vec<PathBuf>,
+    /// `max_files` was reached before every directory was visited.
+    pub truncated: bool,
+    /// Directory symlinks that point back at one of their own ancestors; not followed.
+    pub cycles: Vec<PathBuf>,
+}
+
+/// Walks `root`, skipping what its `.gitignore`/`.ignore` files exclude. Ignore files
+/// above `root` are not consulted.
+pub fn walk(root: &Path, max_files: usize) -> Walk {
+    walk_with(root, max_files, true)
+}
+
+/// Walks `root` without consulting ignore files, for build output that git ignores.
+pub fn walk_all(root: &Path, max_files: usize) -> Walk {
+    walk_with(root, max_files, false)
+}
+
 pub fn walk_files(root: &Path, max_files: usize) -> Vec<PathBuf> {
-    let mut out = Vec::new();
-    let mut stack = vec![root.to_path_buf()];
+    walk(root, max_files).files
+}
 
-    while let Some(dir) = stack.pop() {
-        if out.len() >= max_files {
-            break;
+fn walk_with(root: &Path, max_files: usize, ignores: bool) -> Walk {
+    let mut out = Walk::default();
+    let mut walker = Walker {
+        root,
+        max_files,
+        use_ignores: ignores,
+        ancestors: Vec::new(),
+        out: &mut out,
+    };
+    walker.dir(root, "", &Ignores::new());
+    out
+}
+
+struct Walker<'a> {
+    root: &'a Path,
+    max_files: usize,
+    use_ignores: bool,
+    // Canonical paths of the directories being visited, to spot symlink loops.
+    ancestors: Vec<PathBuf>,
+    out: &'a mut Walk,
+}
+
+impl Walker<'_> {
+    fn dir(&mut self, dir: &Path, rel: &str, parent: &Ignores) {
+        let Ok(canonical) = dir.canonicalize() else {
+            return;
+        };
+        if self.ancestors.contains(&canonical) {
+            self.out.cycles.push(dir.to_path_buf());
+            return;
+        }
+
+        let mut ignores = parent.clone();
+        if self.use_ignores {
+            ignores.load_dir(self.root, rel, &IGNORE_FILES);
         }
 
-        let entries = match fs::read_dir(&dir) {
-            Ok(v) => v,
-            Err(_) => continue,
+        let Ok(entries) = fs::read_dir(dir) else {
+            return;
         };
+        let mut entries: Vec<_> = entries.flatten().collect();
+        entries.sort_by_key(|e| e.file_name());
 
-        for e in entries.flatten() {
-            if out.len() >= max_files {
+        self.ancestors.push(canonical);
+        for e in entries {
+            if self.out.truncated {
                 break;
             }
+            let name = e.file_name().to_string_lossy().to_string();
+            if ALWAYS_SKIPPED.contains(&name.as_str()) {
+                continue;
+            }
             let path = e.path();
-            let name = e.file_name();
-            let name = name.to_string_lossy();
-
-            if name == "node_modules" || name == "target" || name == ".git" {
+            let child = if rel.is_empty() {
+                name
+            } else {
+                format!("{rel}/{name}")
+            };
+            // Follows symlinks, so a link to a directory is walked like one.
+            let is_dir = path.is_dir();
+            if self.use_ignores && ignores.is_ignored(&child, is_dir) {
                 continue;
             }
 
-            if path.is_dir() {
-                stack.push(path);
+            if is_dir {
+                self.dir(&path, &child, &ignores);
+            } else if self.out.files.len() >= self.max_files {
+                self.out.truncated = true;
             } else {
-                out.push(path);
+                self.out.files.push(path);
             }
         }
+        self.ancestors.pop();
     }
+}
 
-    out
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tree(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("walk-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        for (path, text) in files {
+            let path = root.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, text).unwrap();
+        }
+        root
+    }
+
+    fn rel(root: &Path, walk: &Walk) -> Vec<String> {
+        walk.files
+            .iter()
+            .map(|p| {
+                p.strip_prefix(root)
+                    .unwrap()
+                    .to_string_lossy()
+                    .replace('\\', "/")
+            })
+            .collect()
+    }
+
+    #[test]
+    fn skips_build_output_and_vcs_dirs_without_ignore_files() {
+        let root = tree(
+            "skipped",
+            &[
+                ("src/main.rs", ""),
+                ("target/debug/cli", ""),
+                ("node_modules/x/index.js", ""),
+                (".git/HEAD", ""),
+                ("crates/a/target/debug/a", ""),
+            ],
+        );
+        assert_eq!(rel(&root, &walk(&root, 100)), ["src/main.rs"]);
+        assert_eq!(rel(&root, &walk_all(&root, 100)), ["src/main.rs"]);
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn honours_gitignore_only_in_walk() {
+        let root = tree(
+            "ignored",
+            &[
+                (".gitignore", "dist/\n*.log\n!keep.log\n"),
+                ("dist/index.js", ""),
+                ("a.log", ""),
+                ("keep.log", ""),
+                ("b.txt", ""),
+            ],
+        );
+        assert_eq!(
+            rel(&root, &walk(&root, 100)),
+            [".gitignore", "b.txt", "keep.log"]
+        );
+        assert_eq!(
+            rel(&root, &walk_all(&root, 100)),
+            [".gitignore", "a.log", "b.txt", "dist/index.js", "keep.log"]
+        );
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn stops_at_max_files() {
+        let root = tree("truncated", &[("a", ""), ("b", ""), ("c", "")]);
+        let w = walk(&root, 2);
+        assert!(w.truncated);
+        assert_eq!(rel(&root, &w), ["a", "b"]);
+        assert!(!walk(&root, 3).truncated);
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn does_not_follow_symlink_cycles() {
+        let root = tree("cycle", &[("a/file", "")]);
+        std::os::unix::fs::symlink(&root, root.join("a/loop")).unwrap();
+        let w = walk(&root, 100);
+        assert_eq!(rel(&root, &w), ["a/file"]);
+        assert_eq!(w.cycles, [root.join("a/loop")]);
+        fs::remove_dir_all(&root).unwrap();
+    }
 }